@@ -58,6 +58,10 @@ function formatDateTime(value: string | null): string {
   }).format(parsed)
 }
 
+function formatDefinitionLabel(label: string): string {
+  return /^\d+$/.test(label) ? `nr. ${label}` : `bokstav ${label}`
+}
+
 function isRetryEligible(summary: ProposalSummaryState): boolean {
   if (summary.status === "missing") {
     return true
@@ -226,7 +230,7 @@ export function ProposalDetailView({ proposalId }: { proposalId: string }) {
     return null
   }
 
-  const { proposal, linkedDocuments, summary } = payload
+  const { proposal, linkedDocuments, definitions, summary } = payload
 
   return (
     <section className="space-y-6">
@@ -337,6 +341,34 @@ export function ProposalDetailView({ proposalId }: { proposalId: string }) {
         </CardContent>
       </Card>
 
+      {definitions.length > 0 ? (
+        <Card className="border-zinc-300/90 bg-white/95 shadow-sm">
+          <CardHeader>
+            <CardTitle className="font-serif text-2xl text-zinc-900">
+              Definisjoner
+            </CardTitle>
+          </CardHeader>
+          <CardContent>
+            <dl className="space-y-3 text-sm">
+              {definitions.map((entry, index) => (
+                <div key={`${entry.section ?? ""}-${entry.term}-${index}`}>
+                  <dt className="font-medium text-zinc-900">
+                    {entry.term}
+                    {entry.section ? (
+                      <span className="ml-2 text-xs font-normal text-zinc-500">
+                        {entry.section}
+                        {entry.label ? ` ${formatDefinitionLabel(entry.label)}` : ""}
+                      </span>
+                    ) : null}
+                  </dt>
+                  <dd className="leading-relaxed text-zinc-700">{entry.definition}</dd>
+                </div>
+              ))}
+            </dl>
+          </CardContent>
+        </Card>
+      ) : null}
+
       <Card className="border-zinc-300/90 bg-white/95 shadow-sm">
         <CardHeader>
           <CardTitle className="font-serif text-2xl text-zinc-900">
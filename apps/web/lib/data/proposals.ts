@@ -3,6 +3,7 @@ import { createSupabaseServerClient } from "@/lib/supabase-server"
 import type {
   LinkedDocument,
   ListQuery,
+  ProposalDefinition,
   ProposalDetail,
   ProposalDetailResponse,
   ProposalListItem,
@@ -146,6 +147,39 @@ function extractSummaryPayload(value: unknown): ProposalSummaryPayload | null {
   return null
 }
 
+function optionalText(value: unknown): string | null {
+  return typeof value === "string" && value.trim().length > 0 ? value.trim() : null
+}
+
+function extractDefinitions(value: unknown): ProposalDefinition[] {
+  if (!Array.isArray(value)) {
+    return []
+  }
+
+  return value.flatMap((entry) => {
+    if (!entry || typeof entry !== "object") {
+      return []
+    }
+
+    const record = entry as Record<string, unknown>
+    const term = optionalText(record.term)
+    const definition = optionalText(record.definition)
+
+    if (!term || !definition) {
+      return []
+    }
+
+    return [
+      {
+        term,
+        definition,
+        label: optionalText(record.label),
+        section: optionalText(record.section),
+      },
+    ]
+  })
+}
+
 function mapSummaryState(row: {
   generation_status: SummaryGenerationStatus
   summary_payload: unknown
@@ -197,7 +231,7 @@ export async function getProposalDetail(
   const { data: proposal, error: proposalError } = await supabase
     .from("law_proposals")
     .select(
-      "id,title,status,decision_date,enforcement_date,feed_description,stortinget_link,lovdata_link"
+      "id,title,status,decision_date,enforcement_date,feed_description,stortinget_link,lovdata_link,definitions"
     )
     .eq("id", proposalId)
     .maybeSingle()
@@ -253,9 +287,12 @@ export async function getProposalDetail(
     throw createListError("Could not fetch proposal summary", summaryError)
   }
 
+  const { definitions, ...detail } = proposal
+
   return {
-    proposal: proposal as ProposalDetail,
+    proposal: detail as ProposalDetail,
     linkedDocuments,
+    definitions: extractDefinitions(definitions),
     summary: mapSummaryState(summaryRow),
   }
 }
@@ -7,6 +7,7 @@ const ENFORCEMENT_TEXT: Record<string, string> = {
   PARSER_IKKE_FUNNET:
     "Fant ikke ikrafttredelse i teksten automatisk. Se lenke.",
   PARSER_FEIL: "Kunne ikke hente/lese vedtak automatisk. Se lenke.",
+  RELATIV: "Trer i kraft en tid etter kunngjøring eller sanksjon. Se lenke.",
 }
 
 export function formatEnforcementDate(value: string | null): string {
@@ -54,6 +54,13 @@ export interface LinkedDocument {
   document_type: string
 }
 
+export interface ProposalDefinition {
+  term: string
+  definition: string
+  label: string | null
+  section: string | null
+}
+
 export interface PaginationMeta {
   page: number
   pageSize: number
@@ -69,6 +76,7 @@ export interface ProposalListResponse {
 export interface ProposalDetailResponse {
   proposal: ProposalDetail
   linkedDocuments: LinkedDocument[]
+  definitions: ProposalDefinition[]
   summary: ProposalSummaryState
 }
 
@@ -33,6 +33,14 @@ function createPayload(): ProposalDetailResponse {
         document_type: "lov",
       },
     ],
+    definitions: [
+      {
+        term: "skattyter",
+        definition: "enhver som har plikt til å betale skatt",
+        label: "a",
+        section: "§ 1-2",
+      },
+    ],
     summary: {
       status: "ready",
       data: "Forslaget moderniserer skattereglene.",
@@ -63,6 +71,9 @@ describe("ProposalDetailView", () => {
     expect(
       screen.getByText("Forslaget moderniserer skattereglene.")
     ).toBeInTheDocument()
+    expect(screen.getByText("skattyter")).toBeInTheDocument()
+    expect(screen.getByText("enhver som har plikt til å betale skatt")).toBeInTheDocument()
+    expect(screen.getByText("§ 1-2 bokstav a")).toBeInTheDocument()
     
     // Check that dokid is displayed as a link
     const lovdataLink = screen.getByRole("link", { name: "LOV-2017-06-16-60" })
@@ -28,6 +28,7 @@ test("smoke flow: list -> filter -> detail", async ({ page }) => {
             document_type: "lov",
           },
         ],
+        definitions: [],
         summary: {
           status: "ready",
           data: "Forslaget moderniserer skattereglene.",
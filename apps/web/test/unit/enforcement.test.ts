@@ -19,6 +19,9 @@ describe("formatEnforcementDate", () => {
     expect(formatEnforcementDate("PARSER_FEIL")).toBe(
       "Kunne ikke hente/lese vedtak automatisk. Se lenke."
     )
+    expect(formatEnforcementDate("RELATIV")).toBe(
+      "Trer i kraft en tid etter kunngjøring eller sanksjon. Se lenke."
+    )
   })
 
   it("formats ISO dates and falls back for missing/invalid", () => {
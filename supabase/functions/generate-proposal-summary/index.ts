@@ -8,7 +8,11 @@ import {
   Logger,
   withTimeout,
 } from "../shared/logger.ts";
-import { extractOpenAiOutputText, truncateForPrompt } from "./summary.ts";
+import {
+  extractOpenAiOutputText,
+  formatGlossary,
+  truncateForPrompt,
+} from "./summary.ts";
 
 const FUNCTION_NAME = "generate-proposal-summary";
 const OPENAI_MODEL_ID = "gpt-4.1-mini";
@@ -19,6 +23,7 @@ const SOURCE_FETCH_TIMEOUT_MS = 12_000;
 const OPENAI_TIMEOUT_MS = 25_000;
 const MIN_SOURCE_TEXT_LENGTH = 250;
 const MAX_SOURCE_TEXT_CHARS = 12_000;
+const MAX_GLOSSARY_ENTRIES = 20;
 
 const DEFAULT_RETRY_COOLDOWN_SECONDS = 3600;
 const DEFAULT_PENDING_TTL_SECONDS = 180;
@@ -36,6 +41,7 @@ type ProposalRecord = {
   feed_description: string | null;
   stortinget_link: string | null;
   lovdata_link: string | null;
+  definitions: unknown;
 };
 
 type ClaimDecision =
@@ -258,12 +264,14 @@ function createPrompt(
     "Hold deg til det som kan underbygges av kildeteksten.",
   ].join(" ");
 
+  const glossary = formatGlossary(proposal.definitions, MAX_GLOSSARY_ENTRIES);
   const user = [
     `Tittel: ${proposal.title}`,
     `Status: ${proposal.status}`,
     `Beslutningsdato: ${proposal.decision_date ?? "ukjent"}`,
     `Ikrafttredelse: ${proposal.enforcement_date ?? "ukjent"}`,
     `Stortinget-lenke: ${proposal.stortinget_link ?? "ukjent"}`,
+    ...(glossary.length > 0 ? ["", "Definisjoner i loven:", ...glossary] : []),
     "",
     "Oppgave:",
     "Lag en kort og oversiktlig oppsummering av hva forslaget innebærer og hvem som kan bli berørt.",
@@ -538,7 +546,7 @@ Deno.serve(async (req: Request): Promise<Response> => {
       supabase
         .from("law_proposals")
         .select(
-          "id,title,status,decision_date,enforcement_date,feed_description,stortinget_link,lovdata_link",
+          "id,title,status,decision_date,enforcement_date,feed_description,stortinget_link,lovdata_link,definitions",
         )
         .eq("id", proposalId)
         .maybeSingle(),
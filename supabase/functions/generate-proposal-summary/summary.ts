@@ -57,6 +57,21 @@ export function truncateForPrompt(value: string, maxChars: number): string {
   return `${value.slice(0, maxChars)}...`;
 }
 
+// Renders the law matcher's definitions as "term: definition" lines for the prompt.
+export function formatGlossary(value: unknown, maxEntries: number): string[] {
+  if (!Array.isArray(value)) {
+    return [];
+  }
+
+  return value
+    .filter((item): item is Record<string, unknown> =>
+      Boolean(item) && typeof item === "object" &&
+      typeof item.term === "string" && typeof item.definition === "string"
+    )
+    .slice(0, maxEntries)
+    .map((item) => `- ${item.term}: ${item.definition}`);
+}
+
 export function extractOpenAiOutputText(payload: unknown): string | null {
   if (!payload || typeof payload !== "object") {
     return null;
@@ -2,6 +2,7 @@ import {
   extractBetweenComments,
   extractInnholdSection,
   extractOpenAiOutputText,
+  formatGlossary,
   stripHtmlTags,
   truncateForPrompt,
 } from "./summary.ts";
@@ -88,3 +89,22 @@ Deno.test("extractOpenAiOutputText falls back to output content", () => {
     throw new Error(`unexpected fallback output text: ${output}`);
   }
 });
+
+Deno.test("formatGlossary renders valid definitions up to the limit", () => {
+  const lines = formatGlossary([
+    { term: "tilbyder", definition: "enhver som tilbyr en digital tjeneste" },
+    { term: "bruker" },
+    { term: "mellomledd", definition: "en tjeneste som lagrer informasjon" },
+  ], 1);
+
+  if (
+    lines.length !== 1 ||
+    lines[0] !== "- tilbyder: enhver som tilbyr en digital tjeneste"
+  ) {
+    throw new Error(`unexpected glossary: ${lines.join(" | ")}`);
+  }
+
+  if (formatGlossary(null, 5).length !== 0) {
+    throw new Error("expected empty glossary for missing definitions");
+  }
+});
@@ -8,14 +8,66 @@ import {
   Logger,
   withTimeout,
 } from "../shared/logger.ts";
-import { normalizeEnforcementDate, normalizeExtractedIds } from "./payload.ts";
+import {
+  enactedLawColumns,
+  type EuActReference,
+  normalizeDeadlines,
+  normalizeDefinitions,
+  normalizeDelegations,
+  normalizeEffectDate,
+  normalizeEnactedLaw,
+  normalizeEnforcement,
+  normalizeEnforcementCandidates,
+  normalizeEnforcementDate,
+  normalizeEnforcementResolution,
+  normalizeEnforcementSchedule,
+  normalizeEuActs,
+  normalizeExpiry,
+  normalizeExtractedIds,
+  normalizeLanguage,
+  normalizeObligations,
+  normalizePenalties,
+  normalizeReferences,
+  normalizeRelativeEnforcement,
+  normalizeRepealedDocuments,
+  normalizeSubDecisions,
+  normalizeTransitionalProvisions,
+  normalizeTruncation,
+  type ReferenceRole,
+  type SubDecision,
+} from "./payload.ts";
 
 const SUPABASE_TIMEOUT_MS = 10_000;
 
 type RequestPayload = {
   proposal_id?: unknown;
   extracted_ids?: unknown;
+  references?: unknown;
+  eu_acts?: unknown;
+  enacted_law?: unknown;
+  enforcement?: unknown;
   enforcement_date?: unknown;
+  enforcement_schedule?: unknown;
+  enforcement_relative?: unknown;
+  effect_date?: unknown;
+  language?: unknown;
+  enforcement_primary_rule?: unknown;
+  enforcement_ambiguous?: unknown;
+  enforcement_candidates?: unknown;
+  enforcement_resolution?: unknown;
+  transitional_provisions?: unknown;
+  repealed_documents?: unknown;
+  delegations?: unknown;
+  definitions?: unknown;
+  obligations?: unknown;
+  deadlines?: unknown;
+  penalties?: unknown;
+  is_temporary?: unknown;
+  sunset_date?: unknown;
+  sunset_clauses?: unknown;
+  sub_decisions?: unknown;
+  truncated?: unknown;
+  truncation?: unknown;
 };
 
 type MatchErrorClassification = {
@@ -24,6 +76,135 @@ type MatchErrorClassification = {
   classification: "timeout" | "data_integrity_error" | "infrastructure_error";
 };
 
+function countRole(
+  entries: { role: ReferenceRole | null }[],
+  role: ReferenceRole,
+): number {
+  return entries.filter((entry) => entry.role === role).length;
+}
+
+// Acts the current extraction no longer finds are removed, so a re-run after a parser
+// fix or a cached re-extraction does not leave stale acts behind.
+async function replaceEuActs(
+  supabase: ReturnType<typeof createClient>,
+  proposalId: string,
+  euActs: EuActReference[],
+): Promise<void> {
+  const rows = euActs.map((act) => ({
+    proposal_id: proposalId,
+    act_id: act.id,
+    kind: act.kind,
+    celex: act.celex,
+    eli: act.eli,
+  }));
+
+  if (rows.length > 0) {
+    const upsertResult = await withTimeout(
+      supabase
+        .from("proposal_eu_acts")
+        .upsert(rows, { onConflict: "proposal_id,act_id" }),
+      SUPABASE_TIMEOUT_MS,
+    );
+
+    const upsertError = (upsertResult as { error?: unknown }).error;
+    if (upsertError) {
+      throw upsertError;
+    }
+  }
+
+  let staleRows = supabase
+    .from("proposal_eu_acts")
+    .delete()
+    .eq("proposal_id", proposalId);
+  if (rows.length > 0) {
+    const actIds = rows.map((row) => `"${row.act_id}"`).join(",");
+    staleRows = staleRows.not("act_id", "in", `(${actIds})`);
+  }
+
+  const deleteResult = await withTimeout(staleRows, SUPABASE_TIMEOUT_MS);
+
+  const deleteError = (deleteResult as { error?: unknown }).error;
+  if (deleteError) {
+    throw deleteError;
+  }
+}
+
+// Only proposals still waiting for the royal decree or for the anchor of a relative
+// clause are touched, so a date set by hand or by an earlier run is never overwritten.
+// Returns whether a row changed.
+async function applyEnforcementResolution(
+  supabase: ReturnType<typeof createClient>,
+  proposalId: string,
+  fields: Record<string, unknown>,
+): Promise<boolean> {
+  const updateResult = await withTimeout(
+    supabase
+      .from("law_proposals")
+      .update(fields)
+      .eq("id", proposalId)
+      .in("enforcement_date", ["KONGEN_BESTEMMER", "RELATIV"])
+      .select("id"),
+    SUPABASE_TIMEOUT_MS,
+  );
+
+  const { data, error } = updateResult as { data?: unknown[]; error?: unknown };
+  if (error) {
+    throw error;
+  }
+
+  return Array.isArray(data) && data.length > 0;
+}
+
+// Rows are keyed by position within the lovvedtak; positions beyond the current
+// list are removed so a re-run after a parser fix does not leave stale laws behind,
+// including when the decision now yields no sub-decisions at all.
+async function replaceSubDecisions(
+  supabase: ReturnType<typeof createClient>,
+  proposalId: string,
+  subDecisions: SubDecision[],
+): Promise<void> {
+  const rows = subDecisions.map((subDecision, position) => ({
+    proposal_id: proposalId,
+    position,
+    label: subDecision.label,
+    enacted_law_kind: subDecision.enacted_law?.kind ?? null,
+    enacted_law_title: subDecision.enacted_law?.title ?? null,
+    primary_target_legacy_id: subDecision.enacted_law?.primary_target?.id ??
+      null,
+    enforcement_date: subDecision.enforcement_date,
+    enforcement_schedule: subDecision.enforcement_schedule,
+    referenced_ids: subDecision.references.map((reference) => reference.id),
+  }));
+
+  if (rows.length > 0) {
+    const upsertResult = await withTimeout(
+      supabase
+        .from("proposal_sub_decisions")
+        .upsert(rows, { onConflict: "proposal_id,position" }),
+      SUPABASE_TIMEOUT_MS,
+    );
+
+    const upsertError = (upsertResult as { error?: unknown }).error;
+    if (upsertError) {
+      throw upsertError;
+    }
+  }
+
+  const deleteResult = await withTimeout(
+    supabase
+      .from("proposal_sub_decisions")
+      .delete()
+      .eq("proposal_id", proposalId)
+      .gte("position", rows.length),
+    SUPABASE_TIMEOUT_MS,
+  );
+
+  const deleteError = (deleteResult as { error?: unknown }).error;
+  if (deleteError) {
+    throw deleteError;
+  }
+}
+
 function classifyMatchError(error: unknown): MatchErrorClassification {
   if (isTimeoutError(error)) {
     return {
@@ -108,7 +289,141 @@ Deno.serve(async (req: Request): Promise<Response> => {
       ? payload.proposal_id.trim()
       : "";
     const extractedIds = normalizeExtractedIds(payload.extracted_ids);
-    const enforcementDate = normalizeEnforcementDate(payload.enforcement_date);
+    const enforcementDate = payload.enforcement !== undefined
+      ? normalizeEnforcement(payload.enforcement)
+      : normalizeEnforcementDate(payload.enforcement_date);
+    const enforcementSchedule = normalizeEnforcementSchedule(
+      payload.enforcement_schedule,
+    );
+    const enforcementRelative = normalizeRelativeEnforcement(
+      payload.enforcement_relative,
+    );
+    const effectDate = normalizeEffectDate(payload.effect_date);
+    const language = normalizeLanguage(payload.language);
+    const transitionalProvisions = normalizeTransitionalProvisions(
+      payload.transitional_provisions,
+    );
+    const repealedDocuments = normalizeRepealedDocuments(
+      payload.repealed_documents,
+    );
+    const delegations = normalizeDelegations(payload.delegations);
+    const definitions = normalizeDefinitions(payload.definitions);
+    const obligations = normalizeObligations(payload.obligations);
+    const deadlines = normalizeDeadlines(payload.deadlines);
+    const penalties = normalizePenalties(payload.penalties);
+    const expiry = normalizeExpiry(
+      payload.is_temporary,
+      payload.sunset_date,
+      payload.sunset_clauses,
+    );
+    const extractionTruncation = normalizeTruncation(
+      payload.truncated,
+      payload.truncation,
+    );
+    const enforcementCandidates = normalizeEnforcementCandidates(
+      payload.enforcement_candidates,
+    );
+    const enforcementReviewFields = {
+      enforcement_primary_rule: typeof payload.enforcement_primary_rule ===
+          "string"
+        ? payload.enforcement_primary_rule
+        : null,
+      enforcement_ambiguous: payload.enforcement_ambiguous === true,
+      enforcement_candidates: enforcementCandidates,
+    };
+    // Effect date and entry into force are stored side by side; a retroactive law
+    // takes effect before Stortinget adopted it.
+    const effectFields = {
+      effect_date: effectDate?.value ?? null,
+      is_retroactive: effectDate?.retroactive ?? null,
+    };
+    const references = normalizeReferences(payload.references);
+    const roleById = new Map(
+      references.map((reference) => [reference.id, reference.role]),
+    );
+    // A catalogue suggestion is linked alongside the cited ID, flagged for review.
+    const reviewIds = new Set<string>();
+    for (const reference of references) {
+      if (reference.needs_review) {
+        reviewIds.add(reference.id);
+      }
+      if (reference.suggested_id) {
+        reviewIds.add(reference.suggested_id);
+        if (!roleById.has(reference.suggested_id)) {
+          roleById.set(reference.suggested_id, reference.role);
+        }
+      }
+    }
+    const enforcementResolution = normalizeEnforcementResolution(
+      payload.enforcement_resolution,
+    );
+
+    if (enforcementResolution) {
+      if (!proposalId || !enforcementDate) {
+        logger.warn("invalid_resolution_payload", {
+          classification: "expected_error",
+          has_proposal_id: Boolean(proposalId),
+          has_enforcement_date: Boolean(enforcementDate),
+        });
+
+        return errorResponse(
+          400,
+          "Invalid request payload",
+          requestId,
+          "invalid_payload",
+        );
+      }
+
+      try {
+        const updated = await applyEnforcementResolution(
+          createClient(supabaseUrl, supabaseKey),
+          proposalId,
+          {
+            enforcement_date: enforcementDate,
+            enforcement_schedule: enforcementSchedule,
+            ...enforcementReviewFields,
+            enforcement_resolution_id: enforcementResolution.id,
+            enforcement_resolution_url: enforcementResolution.url,
+            enforcement_resolved_at: new Date().toISOString(),
+          },
+        );
+
+        logger.info("enforcement_resolved", {
+          proposal_id: proposalId,
+          enforcement_date: enforcementDate,
+          resolution_id: enforcementResolution.id,
+          updated,
+        });
+
+        return jsonResponse(
+          {
+            status: updated ? "enforcement_resolved" : "already_resolved",
+            proposal_id: proposalId,
+            enforcement_date: enforcementDate,
+            request_id: requestId,
+          },
+          200,
+          requestId,
+        );
+      } catch (error) {
+        const classified = classifyMatchError(error);
+
+        logger.error("enforcement_resolution_failed", error, {
+          proposal_id: proposalId,
+          resolution_id: enforcementResolution.id,
+          classification: classified.classification,
+          retryable: classified.retryable,
+          code: classified.code,
+        });
+
+        return errorResponse(500, "Internal error", requestId, classified.code);
+      }
+    }
+
+    const euActs = normalizeEuActs(payload.eu_acts);
+    const enactedLaw = normalizeEnactedLaw(payload.enacted_law);
+    const enactedFields = enactedLawColumns(enactedLaw);
+    const subDecisions = normalizeSubDecisions(payload.sub_decisions);
 
     if (!proposalId || extractedIds === null || !enforcementDate) {
       logger.warn("invalid_payload", {
@@ -116,6 +431,7 @@ Deno.serve(async (req: Request): Promise<Response> => {
         has_proposal_id: Boolean(proposalId),
         extracted_ids_type: typeof payload.extracted_ids,
         has_enforcement_date: Boolean(enforcementDate),
+        has_versioned_enforcement: payload.enforcement !== undefined,
       });
 
       return errorResponse(
@@ -129,14 +445,53 @@ Deno.serve(async (req: Request): Promise<Response> => {
     const cleanIds = extractedIds
       .map((entry) => entry.trim())
       .filter((entry) => entry.length > 0);
+    const searchIds = [...new Set([...cleanIds, ...reviewIds])];
 
     const supabase = createClient(supabaseUrl, supabaseKey);
+    // PARSER_FEIL without IDs means the extraction failed: stored results are kept and
+    // the proposal is not marked as a new law.
+    const isExtractionFailure = cleanIds.length === 0 &&
+      enforcementDate === "PARSER_FEIL";
 
-    if (cleanIds.length === 0) {
-      // Only mark as new law if extraction was successful but found no IDs
-      // If enforcement_date is PARSER_FEIL, the extraction failed and we shouldn't mark as new law
-      const isExtractionFailure = enforcementDate === "PARSER_FEIL";
+    // A failed extraction carries no EU acts; keep the stored ones.
+    if (!isExtractionFailure) {
+      try {
+        await replaceEuActs(supabase, proposalId, euActs);
+      } catch (error) {
+        const classified = classifyMatchError(error);
 
+        logger.error("eu_acts_upsert_failed", error, {
+          proposal_id: proposalId,
+          eu_acts_count: euActs.length,
+          classification: classified.classification,
+          retryable: classified.retryable,
+          code: classified.code,
+        });
+
+        return errorResponse(500, "Internal error", requestId, classified.code);
+      }
+    }
+
+    // A failed extraction carries no sub-decisions; keep the stored ones.
+    if (!isExtractionFailure) {
+      try {
+        await replaceSubDecisions(supabase, proposalId, subDecisions);
+      } catch (error) {
+        const classified = classifyMatchError(error);
+
+        logger.error("sub_decisions_upsert_failed", error, {
+          proposal_id: proposalId,
+          sub_decisions_count: subDecisions.length,
+          classification: classified.classification,
+          retryable: classified.retryable,
+          code: classified.code,
+        });
+
+        return errorResponse(500, "Internal error", requestId, classified.code);
+      }
+    }
+
+    if (cleanIds.length === 0) {
       if (isExtractionFailure) {
         logger.warn("extraction_failed_skipping_update", {
           proposal_id: proposalId,
@@ -154,9 +509,14 @@ Deno.serve(async (req: Request): Promise<Response> => {
         );
       }
 
+      // Without a heading classification, a decision citing no laws is assumed new.
+      const isNewLaw = enactedLaw ? enactedLaw.kind === "new_law" : true;
+
       logger.info("no_ids_extracted", {
         proposal_id: proposalId,
         enforcement_date: enforcementDate,
+        enforcement_ambiguous: enforcementReviewFields.enforcement_ambiguous,
+        enacted_law_kind: enactedLaw?.kind ?? null,
         classification: "expected_error",
       });
 
@@ -164,7 +524,25 @@ Deno.serve(async (req: Request): Promise<Response> => {
         const updateResult = await withTimeout(
           supabase
             .from("law_proposals")
-            .update({ is_new_law: true, enforcement_date: enforcementDate })
+            .update({
+              is_new_law: isNewLaw,
+              language,
+              transitional_provisions: transitionalProvisions,
+              repealed_documents: repealedDocuments,
+              delegations,
+              definitions,
+              obligations,
+              deadlines,
+              penalties,
+              ...expiry,
+              ...extractionTruncation,
+              enforcement_date: enforcementDate,
+              enforcement_schedule: enforcementSchedule,
+              enforcement_relative: enforcementRelative,
+              ...effectFields,
+              ...enforcementReviewFields,
+              ...enactedFields,
+            })
             .eq("id", proposalId),
           SUPABASE_TIMEOUT_MS,
         );
@@ -189,7 +567,7 @@ Deno.serve(async (req: Request): Promise<Response> => {
 
       return jsonResponse(
         {
-          status: "marked_as_new",
+          status: isNewLaw ? "marked_as_new" : "no_ids_extracted",
           proposal_id: proposalId,
           enforcement_date: enforcementDate,
           request_id: requestId,
@@ -203,7 +581,24 @@ Deno.serve(async (req: Request): Promise<Response> => {
       const enforcementUpdateResult = await withTimeout(
         supabase
           .from("law_proposals")
-          .update({ enforcement_date: enforcementDate })
+          .update({
+            language,
+            transitional_provisions: transitionalProvisions,
+            repealed_documents: repealedDocuments,
+            delegations,
+            definitions,
+            obligations,
+            deadlines,
+            penalties,
+            ...expiry,
+            ...extractionTruncation,
+            enforcement_date: enforcementDate,
+            enforcement_schedule: enforcementSchedule,
+            enforcement_relative: enforcementRelative,
+            ...effectFields,
+            ...enforcementReviewFields,
+            ...enactedFields,
+          })
           .eq("id", proposalId),
         SUPABASE_TIMEOUT_MS,
       );
@@ -227,14 +622,14 @@ Deno.serve(async (req: Request): Promise<Response> => {
       return errorResponse(500, "Internal error", requestId, classified.code);
     }
 
-    let documents: { id: string }[] = [];
+    let documents: { id: string; legacy_id: string | null }[] = [];
 
     try {
       const fetchResult = await withTimeout(
         supabase
           .from("legal_documents")
-          .select("id")
-          .in("legacy_id", cleanIds),
+          .select("id,legacy_id")
+          .in("legacy_id", searchIds),
         SUPABASE_TIMEOUT_MS,
       );
 
@@ -243,7 +638,9 @@ Deno.serve(async (req: Request): Promise<Response> => {
         throw fetchError;
       }
 
-      documents = (fetchResult as { data?: { id: string }[] }).data ?? [];
+      documents = (fetchResult as {
+        data?: { id: string; legacy_id: string | null }[];
+      }).data ?? [];
     } catch (error) {
       const classified = classifyMatchError(error);
 
@@ -282,9 +679,12 @@ Deno.serve(async (req: Request): Promise<Response> => {
     }
 
     try {
+      // Workers predating reference roles only send extracted_ids; leave role unset then.
       const linkEntries = documents.map((document) => ({
         proposal_id: proposalId,
         document_id: document.id,
+        role: roleById.get(document.legacy_id ?? "") ?? null,
+        needs_review: reviewIds.has(document.legacy_id ?? ""),
       }));
 
       const upsertResult = await withTimeout(
@@ -299,12 +699,18 @@ Deno.serve(async (req: Request): Promise<Response> => {
         throw upsertError;
       }
 
+      const reviewCount = linkEntries.filter((entry) => entry.needs_review)
+        .length;
+
       logger.info("linking_completed", {
         proposal_id: proposalId,
         enforcement_date: enforcementDate,
         searched_ids_count: cleanIds.length,
         found_count: documents.length,
         linked_count: linkEntries.length,
+        amended_count: countRole(linkEntries, "amended"),
+        cited_count: countRole(linkEntries, "cited"),
+        needs_review_count: reviewCount,
       });
 
       return jsonResponse(
@@ -1,12 +1,236 @@
-export const ENFORCEMENT_TOKENS = [
-  "KONGEN_BESTEMMER",
-  "STRAKS",
-  "FLERE_DATOER",
-  "PARSER_IKKE_FUNNET",
-  "PARSER_FEIL",
+import enforcementSchema from "../shared/enforcement.schema.json" with {
+  type: "json",
+};
+
+// The schema is generated from the worker's `Enforcement` enum; see
+// workers/stortinget-law-matcher/src/enforcement.rs.
+type SchemaProperty = { type?: string; const?: unknown; format?: string };
+
+type EnforcementVariant = {
+  properties: Record<string, SchemaProperty>;
+  required?: string[];
+  "x-legacy-value"?: string;
+};
+
+const ENFORCEMENT_VARIANTS = enforcementSchema.oneOf as EnforcementVariant[];
+
+export const ENFORCEMENT_WIRE_VERSION = enforcementSchema.properties.version
+  .const;
+
+// Tokens stored in law_proposals.enforcement_date, besides ISO dates.
+export const ENFORCEMENT_TOKENS = ENFORCEMENT_VARIANTS.flatMap((variant) =>
+  variant["x-legacy-value"] ?? []
+);
+
+export const REFERENCE_ROLES = [
+  "amended",
+  "repealed",
+  "cited",
+  "enacting",
+] as const;
+
+export type ReferenceRole = typeof REFERENCE_ROLES[number];
+
+export type LawReference = {
+  id: string;
+  role: ReferenceRole;
+  suggested_id: string | null;
+  needs_review: boolean;
+};
+
+export const EU_ACT_KINDS = [
+  "directive",
+  "regulation",
+  "decision",
+  "eea_annex",
+] as const;
+
+export type EuActKind = typeof EU_ACT_KINDS[number];
+
+export type EuActReference = {
+  id: string;
+  kind: EuActKind;
+  celex: string | null;
+  eli: string | null;
+};
+
+export const ENACTED_LAW_KINDS = [
+  "new_law",
+  "amendment",
+  "repeal",
+  "samlevedtak",
 ] as const;
 
+export type EnactedLawKind = typeof ENACTED_LAW_KINDS[number];
+
+export type EnactedLaw = {
+  title: string;
+  short_title: string | null;
+  kind: EnactedLawKind;
+  primary_target: { title: string; id: string | null } | null;
+};
+
+export const PROVISION_SCOPE_KINDS = [
+  "whole_law",
+  "provisions",
+  "remaining",
+] as const;
+
+export type ProvisionScopeKind = typeof PROVISION_SCOPE_KINDS[number];
+
+export type EnforcementScheduleEntry = {
+  scope: ProvisionScopeKind;
+  provisions: string[];
+  value: string;
+  relative: RelativeEnforcement | null;
+};
+
+export const ENFORCEMENT_ANCHORS = ["promulgation", "sanction"] as const;
+
+export const PERIOD_UNITS = ["days", "weeks", "months", "years"] as const;
+
+export type RelativeEnforcement = {
+  anchor: typeof ENFORCEMENT_ANCHORS[number];
+  offset:
+    | { kind: "on_anchor" }
+    | { kind: "after"; amount: number; unit: typeof PERIOD_UNITS[number] }
+    | { kind: "day_in_following_year"; day: number; month: number };
+};
+
+export type DeadlineLimit =
+  | { kind: "period"; amount: number; unit: typeof PERIOD_UNITS[number] }
+  | { kind: "date"; date: string }
+  | { kind: "annual"; day: number; month: number };
+
+export type Deadline = DeadlineLimit & {
+  anchor: string | null;
+  section: string | null;
+  matched_snippet: string;
+};
+
+export type EnforcementCandidate = {
+  value: string;
+  source: string;
+  matched_snippet: string;
+};
+
+export const AUTHORITY_KINDS = ["king", "ministry", "agency"] as const;
+
+export type Delegation = {
+  authority: string;
+  authority_kind: typeof AUTHORITY_KINDS[number];
+  topic: string;
+  section: string | null;
+  matched_snippet: string;
+};
+
+export type Definition = {
+  term: string;
+  definition: string;
+  label: string | null;
+  section: string | null;
+};
+
+export const OBLIGATION_MODALS = ["shall", "may", "must_not"] as const;
+
+export type Obligation = {
+  addressee: string;
+  modal: typeof OBLIGATION_MODALS[number];
+  action: string;
+  section: string | null;
+  matched_snippet: string;
+};
+
+export const SANCTION_KINDS = [
+  "criminal",
+  "infringement_fee",
+  "coercive_fine",
+] as const;
+
+export const AMOUNT_UNITS = ["kroner", "base_amount"] as const;
+
+export type Penalty = {
+  kind: typeof SANCTION_KINDS[number];
+  section: string | null;
+  fine: boolean;
+  max_imprisonment: { amount: number; unit: typeof PERIOD_UNITS[number] } | null;
+  amounts: { value: number; unit: typeof AMOUNT_UNITS[number] }[];
+  matched_snippet: string;
+};
+
+export const REPEAL_EFFECT_KINDS = [
+  "with_law",
+  "immediate",
+  "by_royal_decree",
+  "date",
+] as const;
+
+export type RepealedDocument = {
+  id: string;
+  title: string | null;
+  effective: typeof REPEAL_EFFECT_KINDS[number];
+  effective_date: string | null;
+};
+
+export const SUNSET_SOURCES = ["repeal", "valid_until"] as const;
+
+export type SunsetClause = {
+  date: string;
+  source: typeof SUNSET_SOURCES[number];
+  matched_snippet: string;
+};
+
+export type LawExpiry = {
+  is_temporary: boolean;
+  sunset_date: string | null;
+  sunset_clauses: SunsetClause[];
+};
+
+export const TRUNCATION_KINDS = ["body", "text", "matches"] as const;
+
+export type ExtractionTruncation = {
+  extraction_truncated: boolean;
+  extraction_truncation: typeof TRUNCATION_KINDS[number][];
+};
+
+export type TransitionalProvision = {
+  section: string | null;
+  provisions: string[];
+  deadline: string | null;
+  excerpt: string;
+  start: number;
+  end: number;
+};
+
+export type EnforcementResolution = {
+  id: string;
+  url: string;
+};
+
+export const LANGUAGES = ["bokmal", "nynorsk"] as const;
+
+export type Language = typeof LANGUAGES[number];
+
+export type EffectDate = {
+  value: string;
+  retroactive: boolean | null;
+};
+
+export type SubDecision = {
+  label: string | null;
+  enacted_law: EnactedLaw | null;
+  references: LawReference[];
+  enforcement_date: string | null;
+  enforcement_schedule: EnforcementScheduleEntry[];
+};
+
 const ENFORCEMENT_SET = new Set<string>(ENFORCEMENT_TOKENS);
+const ENACTED_LAW_KIND_SET = new Set<string>(ENACTED_LAW_KINDS);
+const EU_ACT_KIND_SET = new Set<string>(EU_ACT_KINDS);
+const REFERENCE_ROLE_SET = new Set<string>(REFERENCE_ROLES);
+const ENFORCEMENT_ANCHOR_SET = new Set<string>(ENFORCEMENT_ANCHORS);
+const PERIOD_UNIT_SET = new Set<string>(PERIOD_UNITS);
+const PROVISION_SCOPE_KIND_SET = new Set<string>(PROVISION_SCOPE_KINDS);
 const ISO_DATE_PATTERN = /^\d{4}-\d{2}-\d{2}$/;
 
 function isValidIsoDate(value: string): boolean {
@@ -34,6 +258,174 @@ export function normalizeExtractedIds(value: unknown): string[] | null {
   return null;
 }
 
+function optionalString(value: unknown): string | null {
+  return typeof value === "string" && value.trim().length > 0
+    ? value.trim()
+    : null;
+}
+
+export function normalizeReferences(value: unknown): LawReference[] {
+  if (!Array.isArray(value)) {
+    return [];
+  }
+
+  const references: LawReference[] = [];
+  for (const item of value) {
+    if (!item || typeof item !== "object") {
+      continue;
+    }
+
+    const { id, role, suggested_id, needs_review } = item as Record<
+      string,
+      unknown
+    >;
+    const cleanId = optionalString(id);
+    if (!cleanId) {
+      continue;
+    }
+
+    references.push({
+      id: cleanId,
+      role: typeof role === "string" && REFERENCE_ROLE_SET.has(role)
+        ? role as ReferenceRole
+        : "cited",
+      suggested_id: optionalString(suggested_id),
+      needs_review: needs_review === true,
+    });
+  }
+
+  return references;
+}
+
+export function normalizeEuActs(value: unknown): EuActReference[] {
+  if (!Array.isArray(value)) {
+    return [];
+  }
+
+  const acts: EuActReference[] = [];
+  for (const item of value) {
+    if (!item || typeof item !== "object") {
+      continue;
+    }
+
+    const { id, kind, celex, eli } = item as Record<string, unknown>;
+    const cleanId = optionalString(id);
+    if (!cleanId || typeof kind !== "string" || !EU_ACT_KIND_SET.has(kind)) {
+      continue;
+    }
+
+    acts.push({
+      id: cleanId,
+      kind: kind as EuActKind,
+      celex: optionalString(celex),
+      eli: optionalString(eli),
+    });
+  }
+
+  return acts;
+}
+
+export function normalizeEnactedLaw(value: unknown): EnactedLaw | null {
+  if (!value || typeof value !== "object") {
+    return null;
+  }
+
+  const { title, short_title, kind, primary_target } = value as Record<
+    string,
+    unknown
+  >;
+  const cleanTitle = optionalString(title);
+  if (
+    !cleanTitle || typeof kind !== "string" || !ENACTED_LAW_KIND_SET.has(kind)
+  ) {
+    return null;
+  }
+
+  let primaryTarget: EnactedLaw["primary_target"] = null;
+  if (primary_target && typeof primary_target === "object") {
+    const target = primary_target as Record<string, unknown>;
+    const targetTitle = optionalString(target.title);
+    if (targetTitle) {
+      primaryTarget = { title: targetTitle, id: optionalString(target.id) };
+    }
+  }
+
+  return {
+    title: cleanTitle,
+    short_title: optionalString(short_title),
+    kind: kind as EnactedLawKind,
+    primary_target: primaryTarget,
+  };
+}
+
+// Columns written to law_proposals for a classified decision. The matcher's
+// classification replaces the "no IDs found" heuristic for is_new_law.
+export function enactedLawColumns(
+  enactedLaw: EnactedLaw | null,
+): Record<string, string | boolean | null> {
+  if (!enactedLaw) {
+    return {};
+  }
+
+  return {
+    is_new_law: enactedLaw.kind === "new_law",
+    enacted_law_kind: enactedLaw.kind,
+    enacted_law_title: enactedLaw.title,
+    enacted_law_short_title: enactedLaw.short_title,
+    primary_target_legacy_id: enactedLaw.primary_target?.id ?? null,
+  };
+}
+
+function matchesSchemaProperty(
+  property: SchemaProperty,
+  value: unknown,
+): boolean {
+  if ("const" in property && value !== property.const) {
+    return false;
+  }
+
+  switch (property.type) {
+    case "string":
+      return typeof value === "string" &&
+        (property.format !== "date" || isValidIsoDate(value));
+    case "integer":
+      return Number.isInteger(value);
+    default:
+      return true;
+  }
+}
+
+// Validates an `enforcement` object against the generated schema and returns the
+// value stored in law_proposals.enforcement_date. Nested values (schedule entries,
+// candidates, sub-decisions) share the payload's version and carry none themselves.
+export function normalizeEnforcement(
+  value: unknown,
+  versioned = true,
+): string | null {
+  if (!value || typeof value !== "object") {
+    return null;
+  }
+
+  const record = value as Record<string, unknown>;
+  if (versioned && record.version !== ENFORCEMENT_WIRE_VERSION) {
+    return null;
+  }
+
+  const variant = ENFORCEMENT_VARIANTS.find((candidate) =>
+    (candidate.required ?? []).every((key) => key in record) &&
+    Object.entries(candidate.properties).every(([key, property]) =>
+      !(key in record) || matchesSchemaProperty(property, record[key])
+    )
+  );
+  if (!variant) {
+    return null;
+  }
+
+  return variant["x-legacy-value"] ?? (record.date as string);
+}
+
+// Legacy string form, still sent by workers deployed before the versioned
+// `enforcement` object.
 export function normalizeEnforcementDate(value: unknown): string | null {
   if (typeof value !== "string") {
     return null;
@@ -54,3 +446,586 @@ export function normalizeEnforcementDate(value: unknown): string | null {
 
   return null;
 }
+
+// The worker sends `{ scope: { kind, provisions? }, value, relative, matched_snippet }`;
+// entries are flattened for storage and dropped when the scope or value is unknown.
+// `relative` is the anchor and offset of a RELATIV entry.
+export function normalizeEnforcementSchedule(
+  value: unknown,
+): EnforcementScheduleEntry[] {
+  if (!Array.isArray(value)) {
+    return [];
+  }
+
+  const schedule: EnforcementScheduleEntry[] = [];
+  for (const item of value) {
+    if (!item || typeof item !== "object") {
+      continue;
+    }
+
+    const { scope, value: entryValue, relative } = item as Record<
+      string,
+      unknown
+    >;
+    const cleanValue = normalizeEnforcement(entryValue, false);
+    if (!cleanValue || !scope || typeof scope !== "object") {
+      continue;
+    }
+
+    const { kind, provisions } = scope as Record<string, unknown>;
+    if (typeof kind !== "string" || !PROVISION_SCOPE_KIND_SET.has(kind)) {
+      continue;
+    }
+
+    schedule.push({
+      scope: kind as ProvisionScopeKind,
+      provisions: Array.isArray(provisions)
+        ? provisions.filter((entry): entry is string =>
+          typeof entry === "string"
+        )
+        : [],
+      value: cleanValue,
+      relative: normalizeRelativeEnforcement(relative),
+    });
+  }
+
+  return schedule;
+}
+
+function positiveInteger(value: unknown): number | null {
+  return typeof value === "number" && Number.isInteger(value) && value > 0
+    ? value
+    : null;
+}
+
+export function normalizeRelativeEnforcement(
+  value: unknown,
+): RelativeEnforcement | null {
+  if (!value || typeof value !== "object") {
+    return null;
+  }
+
+  const { anchor, offset } = value as Record<string, unknown>;
+  if (
+    typeof anchor !== "string" || !ENFORCEMENT_ANCHOR_SET.has(anchor) ||
+    !offset || typeof offset !== "object"
+  ) {
+    return null;
+  }
+
+  const cleanAnchor = anchor as RelativeEnforcement["anchor"];
+  const { kind, amount, unit, day, month } = offset as Record<
+    string,
+    unknown
+  >;
+
+  if (kind === "on_anchor") {
+    return { anchor: cleanAnchor, offset: { kind } };
+  }
+
+  if (kind === "after") {
+    const cleanAmount = positiveInteger(amount);
+    if (
+      cleanAmount === null || typeof unit !== "string" ||
+      !PERIOD_UNIT_SET.has(unit)
+    ) {
+      return null;
+    }
+    return {
+      anchor: cleanAnchor,
+      offset: {
+        kind,
+        amount: cleanAmount,
+        unit: unit as typeof PERIOD_UNITS[number],
+      },
+    };
+  }
+
+  if (kind === "day_in_following_year") {
+    const cleanDay = positiveInteger(day);
+    const cleanMonth = positiveInteger(month);
+    if (
+      cleanDay === null || cleanDay > 31 || cleanMonth === null ||
+      cleanMonth > 12
+    ) {
+      return null;
+    }
+    return {
+      anchor: cleanAnchor,
+      offset: { kind, day: cleanDay, month: cleanMonth },
+    };
+  }
+
+  return null;
+}
+
+function normalizeDeadlineLimit(
+  record: Record<string, unknown>,
+): DeadlineLimit | null {
+  const { kind, amount, unit, date, day, month } = record;
+
+  if (kind === "period") {
+    const cleanAmount = positiveInteger(amount);
+    if (
+      cleanAmount === null || typeof unit !== "string" ||
+      !PERIOD_UNIT_SET.has(unit)
+    ) {
+      return null;
+    }
+    return {
+      kind,
+      amount: cleanAmount,
+      unit: unit as typeof PERIOD_UNITS[number],
+    };
+  }
+
+  if (kind === "date") {
+    return typeof date === "string" && isValidIsoDate(date.trim())
+      ? { kind, date: date.trim() }
+      : null;
+  }
+
+  if (kind === "annual") {
+    const cleanDay = positiveInteger(day);
+    const cleanMonth = positiveInteger(month);
+    if (
+      cleanDay === null || cleanDay > 31 || cleanMonth === null ||
+      cleanMonth > 12
+    ) {
+      return null;
+    }
+    return { kind, day: cleanDay, month: cleanMonth };
+  }
+
+  return null;
+}
+
+// Operational time limits (complaint windows, reporting and notification deadlines),
+// kept with the event a period counts from and the § they sit in.
+export function normalizeDeadlines(value: unknown): Deadline[] {
+  if (!Array.isArray(value)) {
+    return [];
+  }
+
+  const deadlines: Deadline[] = [];
+  for (const item of value) {
+    if (!item || typeof item !== "object") {
+      continue;
+    }
+
+    const record = item as Record<string, unknown>;
+    const limit = normalizeDeadlineLimit(record);
+    if (!limit) {
+      continue;
+    }
+
+    deadlines.push({
+      ...limit,
+      anchor: optionalString(record.anchor),
+      section: optionalString(record.section),
+      matched_snippet: optionalString(record.matched_snippet) ?? "",
+    });
+  }
+
+  return deadlines;
+}
+
+// Competing clauses are stored for review; candidates with an unknown value are
+// dropped, as the primary value would have been.
+export function normalizeEnforcementCandidates(
+  value: unknown,
+): EnforcementCandidate[] {
+  if (!Array.isArray(value)) {
+    return [];
+  }
+
+  const candidates: EnforcementCandidate[] = [];
+  for (const item of value) {
+    if (!item || typeof item !== "object") {
+      continue;
+    }
+
+    const { value: candidateValue, source, matched_snippet } = item as Record<
+      string,
+      unknown
+    >;
+    const cleanValue = normalizeEnforcement(candidateValue, false);
+    const cleanSource = optionalString(source);
+    if (!cleanValue || !cleanSource) {
+      continue;
+    }
+
+    candidates.push({
+      value: cleanValue,
+      source: cleanSource,
+      matched_snippet: optionalString(matched_snippet) ?? "",
+    });
+  }
+
+  return candidates;
+}
+
+export function normalizeEffectDate(value: unknown): EffectDate | null {
+  if (!value || typeof value !== "object") {
+    return null;
+  }
+
+  const { value: date, retroactive } = value as Record<string, unknown>;
+  if (typeof date !== "string" || !isValidIsoDate(date.trim())) {
+    return null;
+  }
+
+  return {
+    value: date.trim(),
+    retroactive: typeof retroactive === "boolean" ? retroactive : null,
+  };
+}
+
+// Statutory authorisations to issue regulations ("Kongen kan gi forskrift om ..."),
+// kept as a list of regulations the law will enable.
+export function normalizeDelegations(value: unknown): Delegation[] {
+  if (!Array.isArray(value)) {
+    return [];
+  }
+
+  const delegations: Delegation[] = [];
+  for (const item of value) {
+    if (!item || typeof item !== "object") {
+      continue;
+    }
+
+    const { authority, authority_kind, topic, section, matched_snippet } =
+      item as Record<string, unknown>;
+    const cleanAuthority = optionalString(authority);
+    const kind = AUTHORITY_KINDS.find((entry) => entry === authority_kind);
+    if (!cleanAuthority || !kind) {
+      continue;
+    }
+
+    delegations.push({
+      authority: cleanAuthority,
+      authority_kind: kind,
+      topic: optionalString(topic) ?? "",
+      section: optionalString(section),
+      matched_snippet: optionalString(matched_snippet) ?? "",
+    });
+  }
+
+  return delegations;
+}
+
+// Terms the law defines ("I denne loven menes med: a) ..."), shown as a glossary in the
+// web app and fed to summary generation.
+export function normalizeDefinitions(value: unknown): Definition[] {
+  if (!Array.isArray(value)) {
+    return [];
+  }
+
+  const definitions: Definition[] = [];
+  for (const item of value) {
+    if (!item || typeof item !== "object") {
+      continue;
+    }
+
+    const { term, definition, label, section } = item as Record<string, unknown>;
+    const cleanTerm = optionalString(term);
+    const cleanDefinition = optionalString(definition);
+    if (!cleanTerm || !cleanDefinition) {
+      continue;
+    }
+
+    definitions.push({
+      term: cleanTerm,
+      definition: cleanDefinition,
+      label: optionalString(label),
+      section: optionalString(section),
+    });
+  }
+
+  return definitions;
+}
+
+// Duties, permissions and prohibitions placed on regulated parties, used as the
+// compliance checklist for a proposal.
+export function normalizeObligations(value: unknown): Obligation[] {
+  if (!Array.isArray(value)) {
+    return [];
+  }
+
+  const obligations: Obligation[] = [];
+  for (const item of value) {
+    if (!item || typeof item !== "object") {
+      continue;
+    }
+
+    const { addressee, modal, action, section, matched_snippet } = item as Record<
+      string,
+      unknown
+    >;
+    const cleanAddressee = optionalString(addressee);
+    const cleanModal = OBLIGATION_MODALS.find((entry) => entry === modal);
+    const cleanAction = optionalString(action);
+    if (!cleanAddressee || !cleanModal || !cleanAction) {
+      continue;
+    }
+
+    obligations.push({
+      addressee: cleanAddressee,
+      modal: cleanModal,
+      action: cleanAction,
+      section: optionalString(section),
+      matched_snippet: optionalString(matched_snippet) ?? "",
+    });
+  }
+
+  return obligations;
+}
+
+// Criminal penalties, overtredelsesgebyr and tvangsmulkt per §. Prison terms with an
+// unknown unit and amounts that are not positive numbers are dropped.
+export function normalizePenalties(value: unknown): Penalty[] {
+  if (!Array.isArray(value)) {
+    return [];
+  }
+
+  const penalties: Penalty[] = [];
+  for (const item of value) {
+    if (!item || typeof item !== "object") {
+      continue;
+    }
+
+    const { kind, section, fine, max_imprisonment, amounts, matched_snippet } =
+      item as Record<string, unknown>;
+    const cleanKind = SANCTION_KINDS.find((entry) => entry === kind);
+    if (!cleanKind) {
+      continue;
+    }
+
+    penalties.push({
+      kind: cleanKind,
+      section: optionalString(section),
+      fine: fine === true,
+      max_imprisonment: normalizeImprisonment(max_imprisonment),
+      amounts: normalizePenaltyAmounts(amounts),
+      matched_snippet: optionalString(matched_snippet) ?? "",
+    });
+  }
+
+  return penalties;
+}
+
+function normalizeImprisonment(value: unknown): Penalty["max_imprisonment"] {
+  if (!value || typeof value !== "object") {
+    return null;
+  }
+
+  const { amount, unit } = value as Record<string, unknown>;
+  const cleanAmount = positiveInteger(amount);
+  const cleanUnit = PERIOD_UNITS.find((entry) => entry === unit);
+  return cleanAmount !== null && cleanUnit
+    ? { amount: cleanAmount, unit: cleanUnit }
+    : null;
+}
+
+function normalizePenaltyAmounts(value: unknown): Penalty["amounts"] {
+  if (!Array.isArray(value)) {
+    return [];
+  }
+
+  return value.flatMap((entry) => {
+    if (!entry || typeof entry !== "object") {
+      return [];
+    }
+
+    const { value: amount, unit } = entry as Record<string, unknown>;
+    const cleanUnit = AMOUNT_UNITS.find((candidate) => candidate === unit);
+    return typeof amount === "number" && Number.isFinite(amount) && amount > 0 &&
+        cleanUnit
+      ? [{ value: amount, unit: cleanUnit }]
+      : [];
+  });
+}
+
+// Laws and regulations the lovvedtak repeals in full. The effect is flattened to a
+// kind plus an optional date so downstream can mark `legal_documents` as superseded.
+export function normalizeRepealedDocuments(value: unknown): RepealedDocument[] {
+  if (!Array.isArray(value)) {
+    return [];
+  }
+
+  const repealed: RepealedDocument[] = [];
+  for (const item of value) {
+    if (!item || typeof item !== "object") {
+      continue;
+    }
+
+    const { id, title, effective } = item as Record<string, unknown>;
+    const cleanId = optionalString(id);
+    const effect = effective && typeof effective === "object"
+      ? effective as Record<string, unknown>
+      : {};
+    const kind = REPEAL_EFFECT_KINDS.find((entry) => entry === effect.kind);
+    if (!cleanId || !kind) {
+      continue;
+    }
+    const date = optionalString(effect.date);
+    if (kind === "date" && !(date && isValidIsoDate(date))) {
+      continue;
+    }
+
+    repealed.push({
+      id: cleanId,
+      title: optionalString(title),
+      effective: kind,
+      effective_date: kind === "date" ? date : null,
+    });
+  }
+
+  return repealed;
+}
+
+// A temporary law may have no sunset date ("midlertidig lov" without a clause), but a
+// sunset date always makes the law temporary.
+export function normalizeExpiry(
+  isTemporary: unknown,
+  sunsetDate: unknown,
+  sunsetClauses: unknown,
+): LawExpiry {
+  const clauses: SunsetClause[] = [];
+  for (const item of Array.isArray(sunsetClauses) ? sunsetClauses : []) {
+    if (!item || typeof item !== "object") {
+      continue;
+    }
+
+    const { date, source, matched_snippet } = item as Record<string, unknown>;
+    const cleanDate = optionalString(date);
+    const cleanSource = SUNSET_SOURCES.find((entry) => entry === source);
+    if (!cleanDate || !isValidIsoDate(cleanDate) || !cleanSource) {
+      continue;
+    }
+
+    clauses.push({
+      date: cleanDate,
+      source: cleanSource,
+      matched_snippet: optionalString(matched_snippet) ?? "",
+    });
+  }
+
+  const cleanSunsetDate = optionalString(sunsetDate);
+  const validSunsetDate = cleanSunsetDate && isValidIsoDate(cleanSunsetDate)
+    ? cleanSunsetDate
+    : null;
+
+  return {
+    is_temporary: isTemporary === true || validSunsetDate !== null,
+    sunset_date: validSunsetDate,
+    sunset_clauses: clauses,
+  };
+}
+
+// Set when the worker hit a size limit on a huge page, so the stored results cover only
+// the start of the lovvedtak. A listed limit always marks the extraction as truncated.
+export function normalizeTruncation(
+  truncated: unknown,
+  truncation: unknown,
+): ExtractionTruncation {
+  const kinds: ExtractionTruncation["extraction_truncation"] = [];
+  for (const item of Array.isArray(truncation) ? truncation : []) {
+    const kind = TRUNCATION_KINDS.find((entry) => entry === item);
+    if (kind && !kinds.includes(kind)) {
+      kinds.push(kind);
+    }
+  }
+
+  return {
+    extraction_truncated: truncated === true || kinds.length > 0,
+    extraction_truncation: kinds,
+  };
+}
+
+export function normalizeTransitionalProvisions(
+  value: unknown,
+): TransitionalProvision[] {
+  if (!Array.isArray(value)) {
+    return [];
+  }
+
+  const provisions: TransitionalProvision[] = [];
+  for (const item of value) {
+    if (!item || typeof item !== "object") {
+      continue;
+    }
+
+    const { section, provisions: affected, deadline, excerpt, start, end } =
+      item as Record<string, unknown>;
+    const cleanExcerpt = optionalString(excerpt);
+    if (!cleanExcerpt) {
+      continue;
+    }
+    const cleanDeadline = optionalString(deadline);
+
+    provisions.push({
+      section: optionalString(section),
+      provisions: Array.isArray(affected)
+        ? affected
+          .map(optionalString)
+          .filter((entry): entry is string => entry !== null)
+        : [],
+      deadline: cleanDeadline && isValidIsoDate(cleanDeadline)
+        ? cleanDeadline
+        : null,
+      excerpt: cleanExcerpt,
+      start: typeof start === "number" ? start : 0,
+      end: typeof end === "number" ? end : 0,
+    });
+  }
+
+  return provisions;
+}
+
+// Set by the law matcher's scheduled re-check when a Lovtidend ikrafttredelse
+// resolution replaces KONGEN_BESTEMMER with actual dates.
+export function normalizeEnforcementResolution(
+  value: unknown,
+): EnforcementResolution | null {
+  if (!value || typeof value !== "object") {
+    return null;
+  }
+
+  const { id, url } = value as Record<string, unknown>;
+  if (typeof id !== "string" || !id.trim() || typeof url !== "string") {
+    return null;
+  }
+
+  return { id: id.trim(), url: url.trim() };
+}
+
+export function normalizeLanguage(value: unknown): Language | null {
+  return LANGUAGES.find((language) => language === value) ?? null;
+}
+
+export function normalizeSubDecisions(value: unknown): SubDecision[] {
+  if (!Array.isArray(value)) {
+    return [];
+  }
+
+  return value
+    .filter((item): item is Record<string, unknown> =>
+      Boolean(item) && typeof item === "object"
+    )
+    .map((item) => {
+      const enforcement = item.enforcement && typeof item.enforcement === "object"
+        ? item.enforcement as Record<string, unknown>
+        : {};
+
+      return {
+        label: optionalString(item.label),
+        enacted_law: normalizeEnactedLaw(item.enacted_law),
+        references: normalizeReferences(item.references),
+        enforcement_date: normalizeEnforcement(enforcement.value, false),
+        enforcement_schedule: normalizeEnforcementSchedule(
+          enforcement.schedule,
+        ),
+      };
+    });
+}
@@ -1,4 +1,29 @@
-import { normalizeEnforcementDate, normalizeExtractedIds } from "./payload.ts";
+import {
+  enactedLawColumns,
+  normalizeDeadlines,
+  normalizeDefinitions,
+  normalizeDelegations,
+  normalizeEffectDate,
+  ENFORCEMENT_TOKENS,
+  normalizeEnactedLaw,
+  normalizeEnforcement,
+  normalizeEnforcementCandidates,
+  normalizeEnforcementDate,
+  normalizeEnforcementResolution,
+  normalizeEnforcementSchedule,
+  normalizeEuActs,
+  normalizeExpiry,
+  normalizeExtractedIds,
+  normalizeObligations,
+  normalizePenalties,
+  normalizeLanguage,
+  normalizeReferences,
+  normalizeRelativeEnforcement,
+  normalizeRepealedDocuments,
+  normalizeSubDecisions,
+  normalizeTransitionalProvisions,
+  normalizeTruncation,
+} from "./payload.ts";
 
 Deno.test("normalizeEnforcementDate accepts all sentinel tokens", () => {
   const values = [
@@ -7,6 +32,7 @@ Deno.test("normalizeEnforcementDate accepts all sentinel tokens", () => {
     "FLERE_DATOER",
     "PARSER_IKKE_FUNNET",
     "PARSER_FEIL",
+    "RELATIV",
   ];
 
   for (const value of values) {
@@ -44,3 +70,577 @@ Deno.test("normalizeExtractedIds supports both array and comma-separated string"
     throw new Error("Expected string normalization to split entries");
   }
 });
+
+Deno.test("normalizeReferences keeps valid entries and defaults unknown roles to cited", () => {
+  const references = normalizeReferences([
+    { id: "LOV-2017-06-16-60", role: "amended" },
+    { id: " LOV-1995-03-03-10 ", role: "superseded" },
+    { id: "", role: "repealed" },
+    "LOV-1",
+    null,
+  ]);
+
+  if (references.length !== 2) {
+    throw new Error(`Expected two references, got ${references.length}`);
+  }
+
+  if (references[0].role !== "amended") {
+    throw new Error("Expected known role to be preserved");
+  }
+
+  if (
+    references[1].id !== "LOV-1995-03-03-10" || references[1].role !== "cited"
+  ) {
+    throw new Error("Expected id to be trimmed and unknown role to fall back");
+  }
+});
+
+Deno.test("normalizeReferences keeps catalogue suggestions for review", () => {
+  const [reference] = normalizeReferences([
+    {
+      id: "LOV-2017-06-15-60",
+      role: "amended",
+      suggested_id: "LOV-2014-06-20-43",
+      needs_review: true,
+    },
+  ]);
+
+  if (
+    reference.suggested_id !== "LOV-2014-06-20-43" || !reference.needs_review
+  ) {
+    throw new Error("Expected suggestion and review flag to be preserved");
+  }
+});
+
+Deno.test("normalizeReferences returns empty list for missing field", () => {
+  if (normalizeReferences(undefined).length !== 0) {
+    throw new Error("Expected missing references to normalize to an empty list");
+  }
+});
+
+Deno.test("normalizeEuActs keeps known kinds and nulls missing identifiers", () => {
+  const acts = normalizeEuActs([
+    {
+      id: "32019L1937",
+      kind: "directive",
+      celex: "32019L1937",
+      eli: "http://data.europa.eu/eli/dir/2019/1937/oj",
+    },
+    { id: "EEA-ANNEX-XI", kind: "eea_annex", celex: null, eli: null },
+    { id: "32016R0679", kind: "treaty" },
+    { kind: "regulation" },
+  ]);
+
+  if (acts.length !== 2) {
+    throw new Error(`Expected two EU acts, got ${acts.length}`);
+  }
+
+  if (acts[1].celex !== null || acts[1].eli !== null) {
+    throw new Error("Expected EEA annex to have no CELEX or ELI identifier");
+  }
+});
+
+Deno.test("normalizeEnactedLaw validates kind and primary target", () => {
+  const enactedLaw = normalizeEnactedLaw({
+    title: "Lov om endringer i helseregisterloven mv.",
+    short_title: null,
+    kind: "amendment",
+    primary_target: { title: "helseregisterloven", id: "LOV-2014-06-20-43" },
+  });
+
+  if (enactedLaw?.primary_target?.id !== "LOV-2014-06-20-43") {
+    throw new Error("Expected primary target to be preserved");
+  }
+
+  if (normalizeEnactedLaw({ title: "Lov om x", kind: "unknown" }) !== null) {
+    throw new Error("Expected unknown kind to be rejected");
+  }
+});
+
+Deno.test("enactedLawColumns derives is_new_law from the classification", () => {
+  const amendment = enactedLawColumns({
+    title: "Lov om endringer i skatteloven",
+    short_title: null,
+    kind: "amendment",
+    primary_target: null,
+  });
+
+  if (
+    amendment.is_new_law !== false ||
+    amendment.enacted_law_kind !== "amendment"
+  ) {
+    throw new Error("Expected amendment to be stored as not new");
+  }
+
+  if (Object.keys(enactedLawColumns(null)).length !== 0) {
+    throw new Error("Expected no columns without a classification");
+  }
+});
+
+Deno.test("normalizeSubDecisions keeps per-law classification and enforcement", () => {
+  const subDecisions = normalizeSubDecisions([
+    {
+      label: "A",
+      enacted_law: {
+        title: "Lov om endringer i skatteloven",
+        kind: "amendment",
+        primary_target: { title: "skatteloven", id: "LOV-1999-03-26-14" },
+      },
+      references: [{ id: "LOV-1999-03-26-14", role: "amended" }],
+      enforcement: {
+        value: { kind: "immediate" },
+        source: "straks",
+        schedule: [{
+          scope: { kind: "whole_law" },
+          value: { kind: "immediate" },
+          matched_snippet: "Loven trer i kraft straks.",
+        }],
+      },
+    },
+    {
+      label: "B",
+      enacted_law: null,
+      references: [],
+      enforcement: { value: { kind: "date", date: "not-a-date" } },
+    },
+    "garbage",
+  ]);
+
+  if (subDecisions.length !== 2) {
+    throw new Error(`Expected two sub-decisions, got ${subDecisions.length}`);
+  }
+
+  if (
+    subDecisions[0].enforcement_date !== "STRAKS" ||
+    subDecisions[0].enacted_law?.kind !== "amendment" ||
+    subDecisions[0].enforcement_schedule.length !== 1
+  ) {
+    throw new Error("Expected first sub-decision to keep its own results");
+  }
+
+  if (subDecisions[1].enforcement_date !== null) {
+    throw new Error("Expected invalid enforcement value to normalize to null");
+  }
+});
+
+Deno.test("normalizeEnforcementSchedule flattens scopes and drops unknown entries", () => {
+  const schedule = normalizeEnforcementSchedule([
+    {
+      scope: { kind: "provisions", provisions: ["§ 3", "§ 4"] },
+      value: { kind: "date", date: "2026-07-01" },
+    },
+    {
+      scope: { kind: "remaining" },
+      value: { kind: "date", date: "2027-01-01" },
+    },
+    {
+      scope: { kind: "chapter" },
+      value: { kind: "date", date: "2027-01-01" },
+    },
+    { scope: { kind: "whole_law" }, value: "2027-01-01" },
+  ]);
+
+  if (schedule.length !== 2) {
+    throw new Error(`Expected two schedule entries, got ${schedule.length}`);
+  }
+
+  if (
+    schedule[0].scope !== "provisions" ||
+    schedule[0].provisions.join(",") !== "§ 3,§ 4" ||
+    schedule[1].scope !== "remaining" ||
+    schedule[1].provisions.length !== 0
+  ) {
+    throw new Error("Expected provisions and remaining scopes to be kept");
+  }
+});
+
+Deno.test("normalizeEnforcementSchedule keeps relative provision rules", () => {
+  const schedule = normalizeEnforcementSchedule([
+    {
+      scope: { kind: "provisions", provisions: ["§ 1"] },
+      value: { kind: "date", date: "2027-01-01" },
+      relative: null,
+    },
+    {
+      scope: { kind: "provisions", provisions: ["§ 2"] },
+      value: { kind: "relative" },
+      relative: {
+        anchor: "promulgation",
+        offset: { kind: "after", amount: 3, unit: "months" },
+      },
+    },
+  ]);
+
+  if (
+    schedule.length !== 2 ||
+    schedule[0].relative !== null ||
+    schedule[1].value !== "RELATIV" ||
+    schedule[1].relative?.offset.kind !== "after"
+  ) {
+    throw new Error("Expected the relative rule to be kept on its entry");
+  }
+});
+
+Deno.test("normalizeRelativeEnforcement keeps anchor and offset", () => {
+  const relative = normalizeRelativeEnforcement({
+    anchor: "promulgation",
+    offset: { kind: "after", amount: 6, unit: "months" },
+    matched_snippet: "trer i kraft seks måneder etter kunngjøringen",
+  });
+
+  if (
+    relative?.anchor !== "promulgation" ||
+    relative.offset.kind !== "after" ||
+    relative.offset.amount !== 6 ||
+    relative.offset.unit !== "months"
+  ) {
+    throw new Error("Expected relative enforcement to be kept");
+  }
+
+  const invalid = [
+    null,
+    { anchor: "vote", offset: { kind: "on_anchor" } },
+    { anchor: "sanction", offset: { kind: "after", amount: 0, unit: "days" } },
+    { anchor: "sanction", offset: { kind: "day_in_following_year", day: 1 } },
+  ];
+  for (const value of invalid) {
+    if (normalizeRelativeEnforcement(value) !== null) {
+      throw new Error(`Expected ${JSON.stringify(value)} to be rejected`);
+    }
+  }
+});
+
+Deno.test("normalizeEffectDate keeps ISO date and retroactivity", () => {
+  const effect = normalizeEffectDate({
+    value: "2026-01-01",
+    source: "income_year",
+    retroactive: true,
+  });
+
+  if (effect?.value !== "2026-01-01" || effect.retroactive !== true) {
+    throw new Error("Expected effect date to be kept");
+  }
+
+  if (normalizeEffectDate({ value: "2026-01-01" })?.retroactive !== null) {
+    throw new Error("Expected missing retroactivity to normalize to null");
+  }
+
+  if (normalizeEffectDate({ value: "inntektsåret 2026" }) !== null) {
+    throw new Error("Expected non-ISO effect date to be rejected");
+  }
+});
+
+Deno.test("normalizeEnforcementCandidates keeps competing clauses", () => {
+  const candidates = normalizeEnforcementCandidates([
+    {
+      value: { kind: "immediate" },
+      source: "straks",
+      matched_snippet: "Loven trer i kraft straks.",
+    },
+    {
+      value: { kind: "by_royal_decree" },
+      source: "kongen",
+      matched_snippet: "den Kongen bestemmer, kan gi forskrift",
+    },
+    { value: { kind: "someday" }, source: "fixed_date" },
+    { value: { kind: "immediate" } },
+  ]);
+
+  if (candidates.length !== 2) {
+    throw new Error(`Expected two candidates, got ${candidates.length}`);
+  }
+
+  if (
+    candidates[1].source !== "kongen" ||
+    !candidates[1].matched_snippet.includes("Kongen bestemmer")
+  ) {
+    throw new Error("Expected candidate source and snippet to be kept");
+  }
+});
+
+Deno.test("ENFORCEMENT_TOKENS come from the generated schema", () => {
+  for (
+    const token of [
+      "KONGEN_BESTEMMER",
+      "STRAKS",
+      "FLERE_DATOER",
+      "RELATIV",
+      "PARSER_IKKE_FUNNET",
+      "PARSER_FEIL",
+    ]
+  ) {
+    if (!ENFORCEMENT_TOKENS.includes(token)) {
+      throw new Error(`Expected schema to define ${token}`);
+    }
+  }
+});
+
+Deno.test("normalizeEnforcement maps versioned kinds to stored values", () => {
+  const cases: [unknown, string | null][] = [
+    [{ version: 1, kind: "immediate" }, "STRAKS"],
+    [{ version: 1, kind: "by_royal_decree" }, "KONGEN_BESTEMMER"],
+    [{ version: 1, kind: "date", date: "2027-01-01" }, "2027-01-01"],
+    [{ version: 1, kind: "schedule" }, "FLERE_DATOER"],
+    [{ version: 1, kind: "parser_error" }, "PARSER_FEIL"],
+    [{ version: 1, kind: "date", date: "2027-02-31" }, null],
+    [{ version: 1, kind: "date" }, null],
+    [{ version: 1, kind: "tomorrow" }, null],
+    [{ version: 2, kind: "immediate" }, null],
+    [{ kind: "immediate" }, null],
+    ["STRAKS", null],
+  ];
+
+  for (const [value, expected] of cases) {
+    const normalized = normalizeEnforcement(value);
+    if (normalized !== expected) {
+      throw new Error(
+        `Expected ${JSON.stringify(value)} to normalize to ${expected}, got ${normalized}`,
+      );
+    }
+  }
+
+  if (normalizeEnforcement({ kind: "immediate" }, false) !== "STRAKS") {
+    throw new Error("Expected nested values to be accepted without a version");
+  }
+});
+
+Deno.test("normalizeLanguage accepts known written standards only", () => {
+  if (normalizeLanguage("nynorsk") !== "nynorsk") {
+    throw new Error("Expected nynorsk to be kept");
+  }
+
+  if (normalizeLanguage("Nynorsk") !== null || normalizeLanguage(null) !== null) {
+    throw new Error("Expected unknown languages to normalize to null");
+  }
+});
+
+Deno.test("normalizeEnforcementResolution requires a resolution id", () => {
+  const resolution = normalizeEnforcementResolution({
+    id: " FOR-2025-12-12-2400 ",
+    title: "Ikrafttredelse av lov 20. juni 2025 nr. 85",
+    url: "https://lovdata.no/dokument/LTI/forskrift/2025-12-12-2400",
+  });
+
+  if (resolution?.id !== "FOR-2025-12-12-2400") {
+    throw new Error("Expected resolution id to be trimmed and kept");
+  }
+
+  if (normalizeEnforcementResolution({ url: "https://lovdata.no" }) !== null) {
+    throw new Error("Expected resolution without id to normalize to null");
+  }
+
+  if (normalizeEnforcementResolution(null) !== null) {
+    throw new Error("Expected missing resolution to normalize to null");
+  }
+});
+
+Deno.test("normalizeTransitionalProvisions keeps excerpt, provisions and deadline", () => {
+  const provisions = normalizeTransitionalProvisions([
+    {
+      section: "III",
+      provisions: ["§ 3-5", "", 7],
+      deadline: "2026-07-01",
+      excerpt: "III Overgangsbestemmelser Reglene i § 3-5 ...",
+      start: 120,
+      end: 260,
+    },
+    { section: "IV", deadline: "not-a-date", excerpt: "IV Overgangsregler" },
+    { section: "V" },
+  ]);
+
+  if (provisions.length !== 2) {
+    throw new Error("Expected provisions without excerpt to be dropped");
+  }
+
+  if (
+    provisions[0].provisions.length !== 1 ||
+    provisions[0].deadline !== "2026-07-01" ||
+    provisions[1].deadline !== null
+  ) {
+    throw new Error("Expected provisions and deadlines to be normalized");
+  }
+});
+
+Deno.test("normalizeExpiry flags temporary laws and keeps valid clauses", () => {
+  const expiry = normalizeExpiry(undefined, "2026-12-31", [
+    { date: "2026-12-31", source: "valid_until", matched_snippet: "..." },
+    { date: "2027-07-01", source: "unknown" },
+  ]);
+
+  if (!expiry.is_temporary || expiry.sunset_clauses.length !== 1) {
+    throw new Error("Expected sunset date to mark the law as temporary");
+  }
+
+  const undated = normalizeExpiry(true, "not-a-date", null);
+  if (!undated.is_temporary || undated.sunset_date !== null) {
+    throw new Error("Expected temporary law without valid sunset date");
+  }
+
+  if (normalizeExpiry(undefined, undefined, undefined).is_temporary) {
+    throw new Error("Expected missing expiry to be non-temporary");
+  }
+});
+
+Deno.test("normalizeRepealedDocuments flattens the repeal effect", () => {
+  const repealed = normalizeRepealedDocuments([
+    {
+      id: "LOV-1981-06-05-40",
+      title: "om skilsmisse",
+      effective: { kind: "date", date: "2027-01-01" },
+    },
+    { id: "FOR-2005-07-01-700", effective: { kind: "with_law" } },
+    { id: "LOV-1999-03-26-17", effective: { kind: "date" } },
+    { id: "LOV-1995-03-03-10", effective: { kind: "unknown" } },
+  ]);
+
+  if (repealed.length !== 2) {
+    throw new Error("Expected invalid repeal effects to be dropped");
+  }
+
+  if (
+    repealed[0].effective_date !== "2027-01-01" ||
+    repealed[1].effective !== "with_law" ||
+    repealed[1].effective_date !== null
+  ) {
+    throw new Error("Expected repeal effects to be flattened");
+  }
+});
+
+Deno.test("normalizeDelegations keeps known authority kinds", () => {
+  const delegations = normalizeDelegations([
+    {
+      authority: "Kongen",
+      authority_kind: "king",
+      topic: "om depositumets størrelse",
+      section: "§ 3-5",
+    },
+    { authority: "Stortinget", authority_kind: "parliament", topic: "om alt" },
+    { authority_kind: "ministry" },
+  ]);
+
+  if (
+    delegations.length !== 1 || delegations[0].section !== "§ 3-5" ||
+    delegations[0].matched_snippet !== ""
+  ) {
+    throw new Error("Expected only the valid delegation to be kept");
+  }
+});
+
+Deno.test("normalizeDefinitions drops entries without term or definition", () => {
+  const definitions = normalizeDefinitions([
+    {
+      term: "tilbyder",
+      definition: "enhver som tilbyr en digital tjeneste i Norge",
+      label: "a",
+      section: "§ 2",
+    },
+    { term: "bruker", definition: "  " },
+    { definition: "den myndighet Kongen utpeker" },
+  ]);
+
+  if (
+    definitions.length !== 1 || definitions[0].label !== "a" ||
+    definitions[0].section !== "§ 2"
+  ) {
+    throw new Error("Expected only the complete definition to be kept");
+  }
+});
+
+Deno.test("normalizeObligations keeps known modals with an action", () => {
+  const obligations = normalizeObligations([
+    {
+      addressee: "arbeidsgiver",
+      modal: "shall",
+      action: "varsle Arbeidstilsynet om alvorlige ulykker",
+      section: "§ 4",
+    },
+    { addressee: "kommunen", modal: "should", action: "gi tilskudd" },
+    { addressee: "tilbyder", modal: "must_not", action: "" },
+  ]);
+
+  if (
+    obligations.length !== 1 || obligations[0].section !== "§ 4" ||
+    obligations[0].matched_snippet !== ""
+  ) {
+    throw new Error("Expected only the valid obligation to be kept");
+  }
+});
+
+Deno.test("normalizeDeadlines keeps valid periods, dates and annual deadlines", () => {
+  const deadlines = normalizeDeadlines([
+    {
+      kind: "period",
+      amount: 3,
+      unit: "weeks",
+      anchor: "at vedtaket er mottatt",
+      section: "§ 7",
+    },
+    { kind: "date", date: "2027-07-01" },
+    { kind: "annual", day: 1, month: 3, section: "§ 8" },
+    { kind: "period", amount: 0, unit: "days" },
+    { kind: "date", date: "2027-02-31" },
+    { kind: "annual", day: 1, month: 13 },
+  ]);
+
+  if (
+    deadlines.length !== 3 || deadlines[0].anchor !== "at vedtaket er mottatt" ||
+    deadlines[1].section !== null || deadlines[2].kind !== "annual"
+  ) {
+    throw new Error("Expected only the valid deadlines to be kept");
+  }
+});
+
+Deno.test("normalizePenalties keeps known sanctions with valid terms and amounts", () => {
+  const penalties = normalizePenalties([
+    {
+      kind: "criminal",
+      section: "§ 12",
+      fine: true,
+      max_imprisonment: { amount: 3, unit: "years" },
+      amounts: [],
+    },
+    {
+      kind: "coercive_fine",
+      fine: "yes",
+      max_imprisonment: { amount: 2, unit: "decades" },
+      amounts: [
+        { value: 50000, unit: "kroner" },
+        { value: -1, unit: "kroner" },
+        { value: 15, unit: "euro" },
+      ],
+    },
+    { kind: "warning" },
+  ]);
+
+  if (
+    penalties.length !== 2 || !penalties[0].fine ||
+    penalties[0].max_imprisonment?.amount !== 3 || penalties[1].fine ||
+    penalties[1].max_imprisonment !== null || penalties[1].amounts.length !== 1
+  ) {
+    throw new Error("Expected penalties to be normalized");
+  }
+});
+
+Deno.test("normalizeTruncation keeps known limits only", () => {
+  const truncation = normalizeTruncation(undefined, [
+    "text",
+    "cpu",
+    "text",
+    "matches",
+  ]);
+
+  if (
+    !truncation.extraction_truncated ||
+    truncation.extraction_truncation.join(",") !== "text,matches"
+  ) {
+    throw new Error("Expected known limits once and a truncated extraction");
+  }
+
+  const complete = normalizeTruncation(false, null);
+  if (
+    complete.extraction_truncated ||
+    complete.extraction_truncation.length !== 0
+  ) {
+    throw new Error("Expected a complete extraction without limits");
+  }
+});
@@ -0,0 +1,17 @@
+-- Record how a proposal references each linked document (extracted by the law matcher).
+
+alter table public.proposal_targets
+  add column if not exists role text;
+
+do $$
+begin
+  if not exists (
+    select 1
+    from pg_constraint
+    where conname = 'proposal_targets_role_check'
+  ) then
+    alter table public.proposal_targets
+      add constraint proposal_targets_role_check
+      check (role in ('amended', 'repealed', 'cited', 'enacting'));
+  end if;
+end $$;
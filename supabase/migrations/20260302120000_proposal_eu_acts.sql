@@ -0,0 +1,28 @@
+-- EU/EEA legal acts cited by a proposal, as extracted by the law matcher.
+
+create table if not exists public.proposal_eu_acts (
+  id uuid primary key default gen_random_uuid(),
+  proposal_id uuid not null references public.law_proposals(id) on delete cascade,
+  act_id text not null,
+  kind text not null check (kind in ('directive', 'regulation', 'decision', 'eea_annex')),
+  celex text,
+  eli text,
+  created_at timestamptz not null default now(),
+  unique (proposal_id, act_id)
+);
+
+create index if not exists proposal_eu_acts_celex_idx
+  on public.proposal_eu_acts (celex);
+
+alter table public.proposal_eu_acts enable row level security;
+
+drop policy if exists proposal_eu_acts_select on public.proposal_eu_acts;
+
+create policy proposal_eu_acts_select
+on public.proposal_eu_acts
+for select
+to anon, authenticated
+using (true);
+
+revoke insert, update, delete on table public.proposal_eu_acts from anon, authenticated;
+grant select on table public.proposal_eu_acts to anon, authenticated;
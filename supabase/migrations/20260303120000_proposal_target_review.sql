@@ -0,0 +1,5 @@
+-- Links created from a catalogue-suggested law ID (or an ID the suggestion replaces)
+-- need manual review before they are trusted.
+
+alter table public.proposal_targets
+  add column if not exists needs_review boolean not null default false;
@@ -0,0 +1,20 @@
+-- Classification of what a lovvedtak enacts, read from its heading by the law matcher.
+
+alter table public.law_proposals
+  add column if not exists enacted_law_kind text,
+  add column if not exists enacted_law_title text,
+  add column if not exists enacted_law_short_title text,
+  add column if not exists primary_target_legacy_id text;
+
+do $$
+begin
+  if not exists (
+    select 1
+    from pg_constraint
+    where conname = 'law_proposals_enacted_law_kind_check'
+  ) then
+    alter table public.law_proposals
+      add constraint law_proposals_enacted_law_kind_check
+      check (enacted_law_kind in ('new_law', 'amendment', 'repeal', 'samlevedtak'));
+  end if;
+end $$;
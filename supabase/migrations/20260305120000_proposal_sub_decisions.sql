@@ -0,0 +1,37 @@
+-- Component laws of a lovvedtak. Single-law decisions have one row at position 0;
+-- a samlevedtak has one row per lettered law ("A. Lov om ...", "B. Lov om ...").
+
+create table if not exists public.proposal_sub_decisions (
+  id uuid primary key default gen_random_uuid(),
+  proposal_id uuid not null references public.law_proposals(id) on delete cascade,
+  position integer not null check (position >= 0),
+  label text,
+  enacted_law_kind text check (enacted_law_kind in ('new_law', 'amendment', 'repeal', 'samlevedtak')),
+  enacted_law_title text,
+  primary_target_legacy_id text,
+  enforcement_date text,
+  referenced_ids text[] not null default '{}',
+  created_at timestamptz not null default now(),
+  updated_at timestamptz not null default now(),
+  unique (proposal_id, position)
+);
+
+drop trigger if exists proposal_sub_decisions_set_updated_at on public.proposal_sub_decisions;
+
+create trigger proposal_sub_decisions_set_updated_at
+before update on public.proposal_sub_decisions
+for each row
+execute function public.set_updated_at_timestamp();
+
+alter table public.proposal_sub_decisions enable row level security;
+
+drop policy if exists proposal_sub_decisions_select on public.proposal_sub_decisions;
+
+create policy proposal_sub_decisions_select
+on public.proposal_sub_decisions
+for select
+to anon, authenticated
+using (true);
+
+revoke insert, update, delete on table public.proposal_sub_decisions from anon, authenticated;
+grant select on table public.proposal_sub_decisions to anon, authenticated;
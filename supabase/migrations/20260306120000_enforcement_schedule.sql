@@ -0,0 +1,9 @@
+-- Per-provision entry into force ("§ 3 trer i kraft 1. juli 2026, de øvrige
+-- bestemmelsene 1. januar 2027"). enforcement_date keeps the single overall value,
+-- which is FLERE_DATOER when the schedule holds more than one date.
+
+alter table public.law_proposals
+  add column if not exists enforcement_schedule jsonb not null default '[]'::jsonb;
+
+alter table public.proposal_sub_decisions
+  add column if not exists enforcement_schedule jsonb not null default '[]'::jsonb;
@@ -0,0 +1,6 @@
+-- Entry into force counted from promulgation or sanction ("seks måneder etter
+-- kunngjøringen"). enforcement_date is RELATIV for these; the anchor and offset are
+-- kept here so the date can be resolved once the anchor date is known.
+
+alter table public.law_proposals
+  add column if not exists enforcement_relative jsonb;
@@ -0,0 +1,7 @@
+-- When a law takes effect ("gjelder fra og med inntektsåret 2026", "med virkning fra
+-- 1. januar 2026"), kept apart from enforcement_date. is_retroactive is null when the
+-- decision date was unknown at extraction time.
+
+alter table public.law_proposals
+  add column if not exists effect_date date,
+  add column if not exists is_retroactive boolean;
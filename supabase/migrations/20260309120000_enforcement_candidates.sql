@@ -0,0 +1,11 @@
+-- Every clause the enforcement parser considered, with the rule that picked
+-- enforcement_date. Ambiguous rows had competing values and need a manual check.
+
+alter table public.law_proposals
+  add column if not exists enforcement_primary_rule text,
+  add column if not exists enforcement_ambiguous boolean not null default false,
+  add column if not exists enforcement_candidates jsonb not null default '[]'::jsonb;
+
+create index if not exists law_proposals_enforcement_ambiguous_idx
+  on public.law_proposals (enforcement_ambiguous)
+  where enforcement_ambiguous;
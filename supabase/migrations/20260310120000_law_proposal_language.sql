@@ -0,0 +1,18 @@
+-- Written standard of the lovvedtak (bokmål or nynorsk), detected by the law matcher.
+-- Null for proposals processed before detection existed.
+
+alter table public.law_proposals
+  add column if not exists language text;
+
+do $$
+begin
+  if not exists (
+    select 1
+    from pg_constraint
+    where conname = 'law_proposals_language_check'
+  ) then
+    alter table public.law_proposals
+      add constraint law_proposals_language_check
+      check (language in ('bokmal', 'nynorsk'));
+  end if;
+end $$;
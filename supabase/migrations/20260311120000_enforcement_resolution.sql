@@ -0,0 +1,11 @@
+-- Lovtidend ikrafttredelse resolution that replaced KONGEN_BESTEMMER with actual dates,
+-- set by the law matcher's scheduled re-check.
+
+alter table public.law_proposals
+  add column if not exists enforcement_resolution_id text,
+  add column if not exists enforcement_resolution_url text,
+  add column if not exists enforcement_resolved_at timestamptz;
+
+create index if not exists law_proposals_kongen_bestemmer_idx
+  on public.law_proposals (id)
+  where enforcement_date = 'KONGEN_BESTEMMER';
@@ -0,0 +1,5 @@
+-- Overgangsbestemmelser sections found by the law matcher: affected provisions, any
+-- deadline, an excerpt and the section's offsets in the lovvedtak text.
+
+alter table public.law_proposals
+  add column if not exists transitional_provisions jsonb not null default '[]'::jsonb;
@@ -0,0 +1,12 @@
+-- Temporary laws and when they stop applying ("Loven oppheves 1. juli 2027", "gjelder
+-- til og med 31. desember 2026"). sunset_date is the earliest clause date; a law may be
+-- temporary without one ("midlertidig lov").
+
+alter table public.law_proposals
+  add column if not exists is_temporary boolean not null default false,
+  add column if not exists sunset_date date,
+  add column if not exists sunset_clauses jsonb not null default '[]'::jsonb;
+
+create index if not exists law_proposals_sunset_date_idx
+  on public.law_proposals (sunset_date)
+  where sunset_date is not null;
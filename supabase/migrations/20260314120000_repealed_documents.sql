@@ -0,0 +1,9 @@
+-- Laws and regulations the lovvedtak repeals in full ("Fra den tid loven trer i kraft,
+-- oppheves lov 3. mars 1995 nr. 10 om ..."), keyed by Lovdata dokid with the rule for
+-- when the repeal takes effect.
+
+alter table public.law_proposals
+  add column if not exists repealed_documents jsonb not null default '[]'::jsonb;
+
+create index if not exists law_proposals_repealed_documents_idx
+  on public.law_proposals using gin (repealed_documents jsonb_path_ops);
@@ -0,0 +1,5 @@
+-- Statutory authorisations found by the law matcher ("Kongen kan gi forskrift om ...",
+-- "Departementet kan i forskrift fastsette ..."): authority, topic and the § they sit in.
+
+alter table public.law_proposals
+  add column if not exists delegations jsonb not null default '[]'::jsonb;
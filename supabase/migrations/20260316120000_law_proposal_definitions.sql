@@ -0,0 +1,5 @@
+-- Terms defined by the law ("I denne loven menes med: a) ..."), as found by the law
+-- matcher: term, definition, list letter and the § holding the definitions list.
+
+alter table public.law_proposals
+  add column if not exists definitions jsonb not null default '[]'::jsonb;
@@ -0,0 +1,5 @@
+-- Duties, permissions and prohibitions found by the law matcher ("Arbeidsgiveren skal
+-- varsle ..."): addressee, modal (shall, may, must_not), action and the § they sit in.
+
+alter table public.law_proposals
+  add column if not exists obligations jsonb not null default '[]'::jsonb;
@@ -0,0 +1,5 @@
+-- Time limits found by the law matcher ("innen tre uker etter ...", "senest 1. mars hvert
+-- år"): a period, date or annual day, the event a period counts from and the §.
+
+alter table public.law_proposals
+  add column if not exists deadlines jsonb not null default '[]'::jsonb;
@@ -0,0 +1,6 @@
+-- Sanctions found by the law matcher, one entry per § and kind: criminal penalties
+-- (bot/fengsel), overtredelsesgebyr and tvangsmulkt, with prison terms and amounts in
+-- kroner or G.
+
+alter table public.law_proposals
+  add column if not exists penalties jsonb not null default '[]'::jsonb;
@@ -0,0 +1,6 @@
+-- Set when the law matcher hit a size limit on a huge page (body, text or matches), so
+-- the extracted fields cover only the start of the lovvedtak.
+
+alter table public.law_proposals
+  add column if not exists extraction_truncated boolean not null default false,
+  add column if not exists extraction_truncation jsonb not null default '[]'::jsonb;
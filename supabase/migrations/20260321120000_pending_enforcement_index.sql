@@ -0,0 +1,8 @@
+-- The scheduled re-check serves proposals stored as KONGEN_BESTEMMER and, since relative
+-- clauses are resolved from the sanctioned law, RELATIV. Cover both in one partial index.
+
+drop index if exists public.law_proposals_kongen_bestemmer_idx;
+
+create index if not exists law_proposals_pending_enforcement_idx
+  on public.law_proposals (id)
+  where enforcement_date in ('KONGEN_BESTEMMER', 'RELATIV');
@@ -0,0 +1,76 @@
+//! Timings on a development machine, before and after the regexes were compiled once and
+//! the literal pre-filter added, with the text scanned once per request:
+//!
+//! | benchmark         | before | after  |
+//! |-------------------|--------|--------|
+//! | `fixtures`        | 143 ms | 4.8 ms |
+//! | `large_lovvedtak` | 19.3 s | 0.50 s |
+//!
+//! Almost all of the gain is the compiled-once regexes. Real lovvedtak nearly always
+//! contain "lov", "§" and an in-force clause, so the pre-filter rarely lets an extractor
+//! skip a text. Only references and the in-force schedule try their pattern at the literal
+//! hits alone rather than over the whole text.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use stortinget_law_matcher::run_extractors;
+
+/// The extractor fixtures, one lovvedtak-like document each.
+const DOCUMENTS: [&str; 16] = [
+    include_str!("../src/fixtures/deadlines/deadlines.txt"),
+    include_str!("../src/fixtures/definitions/definitions.txt"),
+    include_str!("../src/fixtures/delegations/delegations.txt"),
+    include_str!("../src/fixtures/enacted_law/new_law.txt"),
+    include_str!("../src/fixtures/enforcement/ambiguous.txt"),
+    include_str!("../src/fixtures/enforcement/effect_date.txt"),
+    include_str!("../src/fixtures/enforcement/relative.txt"),
+    include_str!("../src/fixtures/enforcement/schedule.txt"),
+    include_str!("../src/fixtures/eu_acts/eea_implementation.txt"),
+    include_str!("../src/fixtures/expiry/temporary.txt"),
+    include_str!("../src/fixtures/language/nynorsk.txt"),
+    include_str!("../src/fixtures/obligations/obligations.txt"),
+    include_str!("../src/fixtures/penalties/penalties.txt"),
+    include_str!("../src/fixtures/references/roles.txt"),
+    include_str!("../src/fixtures/repeals/repeals.txt"),
+    include_str!("../src/fixtures/transitional/transitional.txt"),
+];
+
+/// Roughly the size of a large samlevedtak or a new law with many chapters.
+const CORPUS_BYTES: usize = 512 * 1024;
+
+/// Numbers the repeated fixtures as consecutive sections, so section lookups have to walk
+/// a realistic number of headings.
+fn corpus() -> String {
+    let mut corpus = String::with_capacity(CORPUS_BYTES);
+    let mut section = 1;
+    while corpus.len() < CORPUS_BYTES {
+        for document in DOCUMENTS {
+            corpus.push_str(&format!("§ {} Bestemmelser ", section));
+            corpus.push_str(document.trim());
+            corpus.push(' ');
+            section += 1;
+        }
+    }
+    corpus
+}
+
+fn extraction(c: &mut Criterion) {
+    let mut group = c.benchmark_group("extraction");
+    group.sample_size(10);
+
+    let document = DOCUMENTS.join(" ");
+    group.throughput(Throughput::Bytes(document.len() as u64));
+    group.bench_function("fixtures", |b| {
+        b.iter(|| run_extractors(black_box(&document)))
+    });
+
+    let corpus = corpus();
+    group.throughput(Throughput::Bytes(corpus.len() as u64));
+    group.bench_function("large_lovvedtak", |b| {
+        b.iter(|| run_extractors(black_box(&corpus)))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, extraction);
+criterion_main!(benches);
@@ -0,0 +1,149 @@
+use serde::Serialize;
+
+use crate::clamp_to_char_boundary_start;
+
+/// HTML bytes read from stortinget.no before the rest of the body is dropped. The largest
+/// samlevedtak and budget laws run to a few megabytes.
+pub(crate) const MAX_BODY_BYTES: usize = 8 * 1024 * 1024;
+/// Cleaned-text bytes handed to the extractors. Well past the longest lovvedtak, and well
+/// within the Worker CPU limit.
+const MAX_TEXT_BYTES: usize = 1024 * 1024;
+/// Findings kept per extractor.
+const MAX_MATCHES: usize = 500;
+
+/// Which limit cut the extraction short. Sent as `truncation`, with `truncated` set, so a
+/// partial result is never mistaken for a complete one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum Truncation {
+    /// Only the first `MAX_BODY_BYTES` of the HTML were read.
+    Body,
+    /// The cleaned text was cut before extraction.
+    Text,
+    /// An extractor found more results than were kept.
+    Matches,
+}
+
+/// Limits for one extraction, and the record of which ones were hit.
+#[derive(Debug)]
+pub(crate) struct ExtractionBudget {
+    max_text_bytes: usize,
+    max_matches: usize,
+    truncation: Vec<Truncation>,
+}
+
+impl Default for ExtractionBudget {
+    fn default() -> Self {
+        Self::new(MAX_TEXT_BYTES, MAX_MATCHES)
+    }
+}
+
+impl ExtractionBudget {
+    pub(crate) fn new(max_text_bytes: usize, max_matches: usize) -> Self {
+        Self {
+            max_text_bytes,
+            max_matches,
+            truncation: Vec::new(),
+        }
+    }
+
+    pub(crate) fn record(&mut self, truncation: Truncation) {
+        if !self.truncation.contains(&truncation) {
+            self.truncation.push(truncation);
+        }
+    }
+
+    pub(crate) fn truncated(&self) -> bool {
+        !self.truncation.is_empty()
+    }
+
+    pub(crate) fn truncation(&self) -> &[Truncation] {
+        &self.truncation
+    }
+
+    /// Cuts `text` to the byte limit, at the last space before it so no word is split.
+    pub(crate) fn limit_text(&mut self, mut text: String) -> String {
+        if text.len() <= self.max_text_bytes {
+            return text;
+        }
+        let limit = clamp_to_char_boundary_start(&text, self.max_text_bytes);
+        let cut = text[..limit].rfind(' ').unwrap_or(limit);
+        text.truncate(cut);
+        self.record(Truncation::Text);
+        text
+    }
+
+    /// Whether an extractor already holding `kept` findings may add the one it just found.
+    /// When it may not, the cut is recorded and the extractor stops its match loop there.
+    pub(crate) fn allows_match(&mut self, kept: usize) -> bool {
+        if kept < self.max_matches {
+            return true;
+        }
+        self.record(Truncation::Matches);
+        false
+    }
+}
+
+/// Decodes a body that may have been cut inside a multi-byte character: the partial
+/// character is dropped, and any other invalid bytes are replaced.
+pub(crate) fn decode_body(bytes: Vec<u8>) -> String {
+    match String::from_utf8(bytes) {
+        Ok(text) => text,
+        Err(error) => {
+            let utf8_error = error.utf8_error();
+            let mut bytes = error.into_bytes();
+            if utf8_error.error_len().is_none() {
+                bytes.truncate(utf8_error.valid_up_to());
+            }
+            String::from_utf8_lossy(&bytes).into_owned()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cuts_text_at_a_word_boundary() {
+        let mut budget = ExtractionBudget::new(12, 10);
+        let text = budget.limit_text("Loven trer i kraft straks.".to_string());
+
+        assert_eq!(text, "Loven trer");
+        assert_eq!(budget.truncation(), &[Truncation::Text]);
+        assert!(budget.truncated());
+    }
+
+    #[test]
+    fn leaves_text_and_matches_within_budget_alone() {
+        let mut budget = ExtractionBudget::new(100, 2);
+
+        assert_eq!(budget.limit_text("kort".to_string()), "kort");
+        assert!(budget.allows_match(0));
+        assert!(budget.allows_match(1));
+        assert!(!budget.truncated());
+    }
+
+    #[test]
+    fn records_each_limit_once() {
+        let mut budget = ExtractionBudget::new(100, 2);
+        budget.record(Truncation::Body);
+
+        assert!(!budget.allows_match(2));
+        assert!(!budget.allows_match(2));
+        assert_eq!(
+            budget.truncation(),
+            &[Truncation::Body, Truncation::Matches]
+        );
+    }
+
+    #[test]
+    fn drops_a_character_cut_by_the_body_limit() {
+        let mut bytes = "lov om særavgift".as_bytes().to_vec();
+        let cut = "lov om sæ".len() - 1;
+        bytes.truncate(cut);
+
+        assert_eq!(decode_body(bytes), "lov om s");
+        assert_eq!(decode_body(vec![b'a', 0xff, b'b']), "a\u{fffd}b");
+    }
+}
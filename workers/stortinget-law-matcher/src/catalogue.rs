@@ -0,0 +1,255 @@
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::rc::Rc;
+
+use crate::references::LawReference;
+
+/// Minimum length of a cited title before a prefix match against the catalogue is
+/// trusted; shorter titles ("om skatt") match too many laws.
+const MIN_PREFIX_TITLE_CHARS: usize = 15;
+/// How long an isolate reuses a loaded catalogue. `legal_documents` changes with new laws,
+/// not per request, so an hour old snapshot is current enough for cross-checking titles.
+const CATALOGUE_TTL_MS: u64 = 60 * 60 * 1000;
+
+/// One row of the `legal_documents` snapshot, as served by the catalogue endpoint.
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct CatalogueEntry {
+    pub(crate) legacy_id: Option<String>,
+    pub(crate) title: String,
+    #[serde(default)]
+    pub(crate) short_title: Option<String>,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct LawCatalogue {
+    known_ids: HashSet<String>,
+    titles: Vec<(String, String)>,
+}
+
+impl LawCatalogue {
+    pub(crate) fn from_entries(entries: Vec<CatalogueEntry>) -> Self {
+        let mut catalogue = LawCatalogue::default();
+        for entry in entries {
+            let Some(legacy_id) = entry.legacy_id else {
+                continue;
+            };
+            catalogue
+                .titles
+                .push((normalize_title(&entry.title), legacy_id.clone()));
+            if let Some(short_title) = entry.short_title {
+                catalogue
+                    .titles
+                    .push((normalize_title(&short_title), legacy_id.clone()));
+            }
+            catalogue.known_ids.insert(legacy_id);
+        }
+        catalogue
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.known_ids.len()
+    }
+
+    pub(crate) fn contains(&self, legacy_id: &str) -> bool {
+        self.known_ids.contains(legacy_id)
+    }
+
+    /// Returns the single catalogue ID whose title matches `cited_title`. Exact matches win;
+    /// otherwise a long enough cited title may match the start of a catalogue title.
+    pub(crate) fn find_by_title(&self, cited_title: &str) -> Option<&str> {
+        let needle = normalize_title(cited_title);
+        if needle.is_empty() {
+            return None;
+        }
+
+        let exact: HashSet<&str> = self
+            .titles
+            .iter()
+            .filter(|(title, _)| *title == needle)
+            .map(|(_, id)| id.as_str())
+            .collect();
+        if !exact.is_empty() {
+            return single(exact);
+        }
+
+        if needle.chars().count() < MIN_PREFIX_TITLE_CHARS {
+            return None;
+        }
+
+        single(
+            self.titles
+                .iter()
+                .filter(|(title, _)| title.starts_with(&needle))
+                .map(|(_, id)| id.as_str())
+                .collect(),
+        )
+    }
+}
+
+/// A loaded catalogue and when it was fetched, kept between requests served by one isolate.
+pub(crate) struct CatalogueSnapshot {
+    fetched_at_ms: u64,
+    catalogue: Rc<LawCatalogue>,
+}
+
+impl CatalogueSnapshot {
+    pub(crate) fn new(catalogue: LawCatalogue, fetched_at_ms: u64) -> Self {
+        Self {
+            fetched_at_ms,
+            catalogue: Rc::new(catalogue),
+        }
+    }
+
+    /// The catalogue, whatever its age; used when a refresh fails.
+    pub(crate) fn catalogue(&self) -> Rc<LawCatalogue> {
+        Rc::clone(&self.catalogue)
+    }
+
+    /// The catalogue while it is younger than the TTL.
+    pub(crate) fn fresh(&self, now_ms: u64) -> Option<Rc<LawCatalogue>> {
+        (now_ms.saturating_sub(self.fetched_at_ms) < CATALOGUE_TTL_MS).then(|| self.catalogue())
+    }
+}
+
+fn single(ids: HashSet<&str>) -> Option<&str> {
+    if ids.len() == 1 {
+        ids.into_iter().next()
+    } else {
+        None
+    }
+}
+
+/// Lowercases, drops a leading "lov om" and any parenthesised short title, and collapses
+/// whitespace so "Lov om helseregistre (helseregisterloven)" compares equal to a cited
+/// "om helseregistre".
+pub(crate) fn normalize_title(title: &str) -> String {
+    let lowered = title.to_lowercase();
+    let without_parens = match lowered.find('(') {
+        Some(idx) => &lowered[..idx],
+        None => lowered.as_str(),
+    };
+    let trimmed = without_parens.trim();
+    let trimmed = trimmed.strip_prefix("lov ").unwrap_or(trimmed);
+    let trimmed = trimmed.strip_prefix("om ").unwrap_or(trimmed);
+
+    trimmed.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Flags references whose ID is missing from the catalogue but whose cited title, or failing
+/// that its short title, points at exactly one known law. The original ID is kept; the
+/// suggestion is for review.
+pub(crate) fn validate_references(references: &mut [LawReference], catalogue: &LawCatalogue) {
+    for reference in references.iter_mut() {
+        if catalogue.contains(&reference.id) {
+            continue;
+        }
+        let Some(suggested) = [&reference.title, &reference.short_title]
+            .into_iter()
+            .flatten()
+            .find_map(|title| catalogue.find_by_title(title))
+        else {
+            continue;
+        };
+        if suggested != reference.id {
+            reference.suggested_id = Some(suggested.to_string());
+            reference.needs_review = true;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::budget::ExtractionBudget;
+    use crate::prefilter::LiteralHits;
+    use crate::references::extract_law_references;
+
+    fn catalogue() -> LawCatalogue {
+        let entries: Vec<CatalogueEntry> =
+            serde_json::from_str(include_str!("fixtures/catalogue/legal_documents.json")).unwrap();
+        LawCatalogue::from_entries(entries)
+    }
+
+    #[test]
+    fn suggests_corrected_id_for_unknown_citation_with_known_title() {
+        let mut references = extract_law_references("I lov 15. juni 2017 nr. 60 om helseregistre og behandling av helseopplysninger gjøres følgende endringer:", &LiteralHits::scan("I lov 15. juni 2017 nr. 60 om helseregistre og behandling av helseopplysninger gjøres følgende endringer:"), &mut ExtractionBudget::default());
+        validate_references(&mut references, &catalogue());
+
+        assert_eq!(references[0].id, "LOV-2017-06-15-60");
+        assert_eq!(
+            references[0].suggested_id.as_deref(),
+            Some("LOV-2014-06-20-43")
+        );
+        assert!(references[0].needs_review);
+    }
+
+    #[test]
+    fn leaves_known_ids_untouched() {
+        let mut references = extract_law_references(
+            "Se lov 20. juni 2014 nr. 43 om helseregistre og behandling av helseopplysninger.",
+            &LiteralHits::scan(
+                "Se lov 20. juni 2014 nr. 43 om helseregistre og behandling av helseopplysninger.",
+            ),
+            &mut ExtractionBudget::default(),
+        );
+        validate_references(&mut references, &catalogue());
+
+        assert_eq!(references[0].suggested_id, None);
+        assert!(!references[0].needs_review);
+    }
+
+    #[test]
+    fn does_not_suggest_when_title_is_ambiguous_or_too_short() {
+        let catalogue = catalogue();
+
+        assert_eq!(catalogue.find_by_title("om skatt av formue"), None);
+        assert_eq!(catalogue.find_by_title("om arbeidsmiljø"), None);
+        assert_eq!(
+            catalogue.find_by_title("om arbeidsmiljø, arbeidstid og stillingsvern"),
+            Some("LOV-2005-06-17-62")
+        );
+    }
+
+    #[test]
+    fn suggests_ids_from_cited_comma_titles_and_short_titles() {
+        let catalogue = catalogue();
+        let mut references = extract_law_references(
+            "Reglene gjelder etter lov 17. juni 2005 nr. 26 om arbeidsmiljø, arbeidstid og \
+             stillingsvern mv. (arbeidsmiljøloven) og lov 26. mars 1998 nr. 14 (skatteloven).",
+            &LiteralHits::scan(
+                "Reglene gjelder etter lov 17. juni 2005 nr. 26 om arbeidsmiljø, arbeidstid og \
+             stillingsvern mv. (arbeidsmiljøloven) og lov 26. mars 1998 nr. 14 (skatteloven).",
+            ),
+            &mut ExtractionBudget::default(),
+        );
+        validate_references(&mut references, &catalogue);
+
+        assert_eq!(
+            references[0].suggested_id.as_deref(),
+            Some("LOV-2005-06-17-62")
+        );
+        assert_eq!(
+            references[1].suggested_id.as_deref(),
+            Some("LOV-1999-03-26-14")
+        );
+        assert!(references.iter().all(|reference| reference.needs_review));
+    }
+
+    #[test]
+    fn snapshot_is_reused_until_the_ttl() {
+        let snapshot = CatalogueSnapshot::new(catalogue(), 1_000);
+
+        assert_eq!(snapshot.fresh(1_000).map(|c| c.len()), Some(4));
+        assert!(snapshot.fresh(1_000 + CATALOGUE_TTL_MS - 1).is_some());
+        assert!(snapshot.fresh(1_000 + CATALOGUE_TTL_MS).is_none());
+        assert_eq!(snapshot.catalogue().len(), 4);
+    }
+
+    #[test]
+    fn matches_short_titles() {
+        assert_eq!(
+            catalogue().find_by_title("helseregisterloven"),
+            Some("LOV-2014-06-20-43")
+        );
+    }
+}
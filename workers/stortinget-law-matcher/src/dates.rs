@@ -0,0 +1,468 @@
+use serde::{Deserialize, Serialize};
+
+/// Full month names come first so "januar" is not cut short at the abbreviation "jan".
+const MONTHS: [(&str, u32); 25] = [
+    ("januar", 1),
+    ("februar", 2),
+    ("mars", 3),
+    ("april", 4),
+    ("mai", 5),
+    ("juni", 6),
+    ("juli", 7),
+    ("august", 8),
+    ("september", 9),
+    ("oktober", 10),
+    ("november", 11),
+    ("desember", 12),
+    ("jan", 1),
+    ("febr", 2),
+    ("feb", 2),
+    ("mar", 3),
+    ("apr", 4),
+    ("jun", 6),
+    ("jul", 7),
+    ("aug", 8),
+    ("sept", 9),
+    ("sep", 9),
+    ("okt", 10),
+    ("nov", 11),
+    ("des", 12),
+];
+
+/// Bokmål and nynorsk ordinals up to 20, and 30. Compounds such as "tjueførste" and
+/// "trettifyrste" are built from the units.
+const ORDINALS: [(&str, u32); 38] = [
+    ("første", 1),
+    ("fyrste", 1),
+    ("andre", 2),
+    ("annen", 2),
+    ("tredje", 3),
+    ("fjerde", 4),
+    ("femte", 5),
+    ("sjette", 6),
+    ("sjuende", 7),
+    ("syvende", 7),
+    ("sjuande", 7),
+    ("åttende", 8),
+    ("åttande", 8),
+    ("niende", 9),
+    ("niande", 9),
+    ("tiende", 10),
+    ("tiande", 10),
+    ("ellevte", 11),
+    ("tolvte", 12),
+    ("trettende", 13),
+    ("trettande", 13),
+    ("fjortende", 14),
+    ("fjortande", 14),
+    ("femtende", 15),
+    ("femtande", 15),
+    ("sekstende", 16),
+    ("sekstande", 16),
+    ("syttende", 17),
+    ("syttande", 17),
+    ("attende", 18),
+    ("attande", 18),
+    ("nittende", 19),
+    ("nittande", 19),
+    ("tjuende", 20),
+    ("tjuande", 20),
+    ("tyvende", 20),
+    ("trettiende", 30),
+    ("trettiande", 30),
+];
+
+/// Bokmål and nynorsk period units, longest forms first.
+pub(crate) const PERIOD_UNIT_PATTERN: &str =
+    r"dager|dagar|dag|uker|veker|uke|veke|måneder|månader|måned|månad|år";
+
+/// "inntektsåret 2026", "skatteåret 2026", "kalenderåret 2026".
+const YEAR_PATTERN: &str = r"(?:det\s+)?(?:inntekts|skatte|kalender)året\s+\d{4}";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum PeriodUnit {
+    Days,
+    Weeks,
+    Months,
+    Years,
+}
+
+/// A date or date rule as written in the decision text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum DateExpression {
+    /// "1. juli 2026", "1 jul. 2026", "01.07.2026", "første juli 2026"
+    Date(String),
+    /// "1. mars" without a year, a day that recurs every year.
+    DayMonth { day: u32, month: u32 },
+    /// "1.–3. juli 2026", both ends as ISO dates.
+    Range { start: String, end: String },
+    /// "inntektsåret 2026"
+    IncomeYear(u32),
+}
+
+impl DateExpression {
+    /// The first day the expression covers, when it names a year.
+    pub(crate) fn first_date(&self) -> Option<String> {
+        match self {
+            DateExpression::Date(date) => Some(date.clone()),
+            DateExpression::Range { start, .. } => Some(start.clone()),
+            DateExpression::IncomeYear(year) => Some(format!("{}-01-01", year)),
+            DateExpression::DayMonth { .. } => None,
+        }
+    }
+}
+
+/// A date expression and its byte span in the searched text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct DateSpan {
+    pub(crate) expression: DateExpression,
+    pub(crate) start: usize,
+    pub(crate) end: usize,
+}
+
+/// Regex fragment for a complete date, without capture groups so extractors can embed it
+/// in their own patterns. Callers add `(?i)`.
+pub(crate) fn date_pattern() -> String {
+    format!(
+        r"(?:\d{{1,2}}\.\d{{1,2}}\.\d{{4}}|(?:{day})\s*(?:{month})\s+\d{{4}})",
+        day = day_pattern(),
+        month = month_pattern()
+    )
+}
+
+/// Regex fragment for a day and month without a year ("1. mars").
+pub(crate) fn day_month_pattern() -> String {
+    format!(
+        r"(?:(?:{day})\s*(?:{month}))",
+        day = day_pattern(),
+        month = month_pattern()
+    )
+}
+
+/// Regex fragment for any date expression: a range, a complete date, an income year or
+/// a day and month. Parse the match with `parse_date_expression`.
+pub(crate) fn date_expression_pattern() -> String {
+    format!(
+        r"(?:\d{{1,2}}\.?\s*[–-]\s*{date}|{date}|{year}|{day_month})",
+        date = date_pattern(),
+        year = YEAR_PATTERN,
+        day_month = day_month_pattern()
+    )
+}
+
+/// All date expressions in `text`, in order.
+pub(crate) fn find_date_expressions(text: &str) -> Vec<DateSpan> {
+    let expression_re = cached_regex!(format!(r"(?i)\b{}", date_expression_pattern()));
+    expression_re
+        .find_iter(text)
+        .filter_map(|m| {
+            Some(DateSpan {
+                expression: parse_date_expression(m.as_str())?,
+                start: m.start(),
+                end: m.end(),
+            })
+        })
+        .collect()
+}
+
+pub(crate) fn parse_date_expression(value: &str) -> Option<DateExpression> {
+    let value = value.trim().to_lowercase();
+    let year_re = cached_regex!(format!("^{}$", YEAR_PATTERN));
+    let range_re = cached_regex!(r"^(\d{1,2})\.?\s*[–-]\s*(.+)$");
+
+    if year_re.is_match(&value) {
+        let year = value[value.len() - 4..].parse::<u32>().ok()?;
+        return Some(DateExpression::IncomeYear(year));
+    }
+    if let Some(cap) = range_re.captures(&value) {
+        let end = parse_date(&cap[2])?;
+        let start = format!("{}-{:0>2}", &end[..7], cap[1].parse::<u32>().ok()?);
+        return (parse_iso(&start).is_some() && start <= end)
+            .then_some(DateExpression::Range { start, end });
+    }
+    if let Some(date) = parse_date(&value) {
+        return Some(DateExpression::Date(date));
+    }
+    parse_day_month(&value).map(|(day, month)| DateExpression::DayMonth { day, month })
+}
+
+/// Parses a complete date into an ISO date, rejecting dates such as "31. februar 2027".
+pub(crate) fn parse_date(value: &str) -> Option<String> {
+    let value = value.trim().to_lowercase();
+    let numeric_re = cached_regex!(r"^(\d{1,2})\.(\d{1,2})\.(\d{4})$");
+    let named_re = cached_regex!(r"^(\d{1,2}|[a-zæøå]+)\.?\s*([a-zæøå]+)\.?\s+(\d{4})$");
+
+    let (day, month, year) = if let Some(cap) = numeric_re.captures(&value) {
+        (
+            cap[1].parse::<u32>().ok()?,
+            cap[2].parse::<u32>().ok()?,
+            cap[3].parse::<u32>().ok()?,
+        )
+    } else {
+        let cap = named_re.captures(&value)?;
+        (
+            day_number(&cap[1])?,
+            month_number(&cap[2])?,
+            cap[3].parse::<u32>().ok()?,
+        )
+    };
+
+    is_valid_date(year, month, day).then(|| format_iso(year, month, day))
+}
+
+/// Parses "1. mars" into day and month. Any leap year works for the check, so
+/// "29. februar" is accepted.
+pub(crate) fn parse_day_month(value: &str) -> Option<(u32, u32)> {
+    let value = value.trim().to_lowercase();
+    let day_month_re = cached_regex!(r"^(\d{1,2}|[a-zæøå]+)\.?\s*([a-zæøå]+)\.?$");
+    let cap = day_month_re.captures(&value)?;
+    let (day, month) = (day_number(&cap[1])?, month_number(&cap[2])?);
+    is_valid_date(2024, month, day).then_some((day, month))
+}
+
+/// The month number for a Norwegian month name or abbreviation ("sept.").
+pub(crate) fn month_number(month: &str) -> Option<u32> {
+    let month = month.trim_end_matches('.').to_lowercase();
+    MONTHS
+        .iter()
+        .find(|(name, _)| *name == month)
+        .map(|(_, number)| *number)
+}
+
+pub(crate) fn is_valid_date(year: u32, month: u32, day: u32) -> bool {
+    if !(1..=12).contains(&month) {
+        return false;
+    }
+
+    let days_in_month = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 => {
+            // Check for leap year
+            if (year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400) {
+                29
+            } else {
+                28
+            }
+        }
+        _ => return false,
+    };
+
+    (1..=days_in_month).contains(&day)
+}
+
+/// Splits an ISO date ("2026-07-01") into year, month and day, rejecting invalid dates.
+pub(crate) fn parse_iso(value: &str) -> Option<(u32, u32, u32)> {
+    let mut parts = value.splitn(3, '-');
+    let year = parts.next()?.parse::<u32>().ok()?;
+    let month = parts.next()?.parse::<u32>().ok()?;
+    let day = parts.next()?.parse::<u32>().ok()?;
+    is_valid_date(year, month, day).then_some((year, month, day))
+}
+
+pub(crate) fn format_iso(year: u32, month: u32, day: u32) -> String {
+    format!("{}-{:0>2}-{:0>2}", year, month, day)
+}
+
+pub(crate) fn period_unit(unit: &str) -> PeriodUnit {
+    match unit.to_lowercase().as_str() {
+        "dag" | "dager" | "dagar" => PeriodUnit::Days,
+        "uke" | "uker" | "veke" | "veker" => PeriodUnit::Weeks,
+        "måned" | "måneder" | "månad" | "månader" => PeriodUnit::Months,
+        _ => PeriodUnit::Years,
+    }
+}
+
+/// Digits or the number words used for enforcement periods and deadlines.
+pub(crate) fn amount_value(value: &str) -> Option<u32> {
+    if let Ok(amount) = value.parse::<u32>() {
+        return (amount > 0).then_some(amount);
+    }
+
+    match value.to_lowercase().as_str() {
+        "en" | "én" | "ett" | "ei" | "ein" | "eitt" => Some(1),
+        "to" => Some(2),
+        "tre" => Some(3),
+        "fire" => Some(4),
+        "fem" => Some(5),
+        "seks" => Some(6),
+        "sju" | "syv" => Some(7),
+        "åtte" => Some(8),
+        "ni" => Some(9),
+        "ti" => Some(10),
+        "elleve" => Some(11),
+        "tolv" => Some(12),
+        "atten" => Some(18),
+        "tjuefire" => Some(24),
+        _ => None,
+    }
+}
+
+fn day_number(day: &str) -> Option<u32> {
+    if let Ok(day) = day.parse::<u32>() {
+        return Some(day);
+    }
+    ordinal_words()
+        .into_iter()
+        .find(|(word, _)| word == day)
+        .map(|(_, value)| value)
+}
+
+/// Every ordinal day word, longest first so alternations prefer "tjueførste" over
+/// "tjuende".
+fn ordinal_words() -> Vec<(String, u32)> {
+    let mut words: Vec<(String, u32)> = ORDINALS
+        .iter()
+        .map(|(word, value)| (word.to_string(), *value))
+        .collect();
+    for (unit, value) in ORDINALS.iter().filter(|(_, value)| *value < 10) {
+        words.push((format!("tjue{}", unit), 20 + value));
+        if *value == 1 {
+            words.push((format!("tretti{}", unit), 31));
+        }
+    }
+    words.sort_by_key(|(word, _)| std::cmp::Reverse(word.chars().count()));
+    words
+}
+
+fn day_pattern() -> String {
+    let ordinals: Vec<String> = ordinal_words().into_iter().map(|(word, _)| word).collect();
+    format!(r"\d{{1,2}}\.?|(?:{})\b", ordinals.join("|"))
+}
+
+fn month_pattern() -> String {
+    let names: Vec<String> = MONTHS
+        .iter()
+        .map(|(name, number)| {
+            if MONTHS[(*number - 1) as usize].0 == *name {
+                format!(r"{}\b", name)
+            } else {
+                format!(r"{}(?:\.|\b)", name)
+            }
+        })
+        .collect();
+    names.join("|")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_valid_date_accepts_valid_dates() {
+        assert!(is_valid_date(2024, 1, 31)); // January 31
+        assert!(is_valid_date(2024, 4, 30)); // April 30
+        assert!(is_valid_date(2024, 2, 29)); // Feb 29 in leap year
+        assert!(is_valid_date(2000, 2, 29)); // Feb 29 in leap year (divisible by 400)
+    }
+
+    #[test]
+    fn is_valid_date_rejects_invalid_dates() {
+        assert!(!is_valid_date(2024, 2, 30)); // Feb 30 doesn't exist
+        assert!(!is_valid_date(2027, 2, 31)); // Feb 31 doesn't exist
+        assert!(!is_valid_date(2023, 2, 29)); // Feb 29 in non-leap year
+        assert!(!is_valid_date(1900, 2, 29)); // Feb 29 in non-leap year (divisible by 100 but not 400)
+        assert!(!is_valid_date(2024, 4, 31)); // April 31 doesn't exist
+        assert!(!is_valid_date(2024, 6, 31)); // June 31 doesn't exist
+        assert!(!is_valid_date(2024, 9, 31)); // September 31 doesn't exist
+        assert!(!is_valid_date(2024, 11, 31)); // November 31 doesn't exist
+        assert!(!is_valid_date(2024, 0, 15)); // Month 0 doesn't exist
+        assert!(!is_valid_date(2024, 13, 15)); // Month 13 doesn't exist
+        assert!(!is_valid_date(2024, 1, 0)); // Day 0 doesn't exist
+        assert!(!is_valid_date(2024, 1, 32)); // Day 32 doesn't exist
+    }
+
+    #[test]
+    fn month_number_maps_names_and_abbreviations() {
+        assert_eq!(month_number("januar"), Some(1));
+        assert_eq!(month_number("Desember"), Some(12));
+        assert_eq!(month_number("sept."), Some(9));
+        assert_eq!(month_number("febr"), Some(2));
+        assert_eq!(month_number("not-a-month"), None);
+    }
+
+    #[test]
+    fn reads_iso_dates_and_period_amounts() {
+        assert_eq!(parse_iso("2024-02-29"), Some((2024, 2, 29)));
+        assert_eq!(parse_iso("2023-02-29"), None);
+        assert_eq!(format_iso(2026, 7, 1), "2026-07-01");
+        assert_eq!(amount_value("Seks"), Some(6));
+        assert_eq!(amount_value("0"), None);
+        assert_eq!(period_unit("veker"), PeriodUnit::Weeks);
+    }
+
+    #[test]
+    fn parses_written_forms_of_a_date() {
+        for value in [
+            "1. juli 2026",
+            "1 juli 2026",
+            "1.juli 2026",
+            "1. jul. 2026",
+            "01.07.2026",
+            "1.7.2026",
+            "første juli 2026",
+            "fyrste juli 2026",
+        ] {
+            assert_eq!(parse_date(value).as_deref(), Some("2026-07-01"), "{value}");
+        }
+
+        assert_eq!(
+            parse_date("tjueførste sept. 2026").as_deref(),
+            Some("2026-09-21")
+        );
+        assert_eq!(
+            parse_date("trettifyrste desember 2026").as_deref(),
+            Some("2026-12-31")
+        );
+        assert_eq!(parse_date("31. februar 2027"), None);
+        assert_eq!(parse_date("1. smarch 2027"), None);
+    }
+
+    #[test]
+    fn parses_date_rules() {
+        assert_eq!(
+            parse_date_expression("1.–3. juli 2026"),
+            Some(DateExpression::Range {
+                start: "2026-07-01".to_string(),
+                end: "2026-07-03".to_string()
+            })
+        );
+        assert_eq!(
+            parse_date_expression("inntektsåret 2026"),
+            Some(DateExpression::IncomeYear(2026))
+        );
+        assert_eq!(
+            parse_date_expression("1. mars"),
+            Some(DateExpression::DayMonth { day: 1, month: 3 })
+        );
+        assert_eq!(parse_date_expression("5.–3. juli 2026"), None);
+        assert_eq!(
+            DateExpression::IncomeYear(2026).first_date().as_deref(),
+            Some("2026-01-01")
+        );
+    }
+
+    #[test]
+    fn finds_date_expressions_with_spans() {
+        let text = "Fristen er 1. mars. Loven gjelder fra inntektsåret 2026, og \
+                    høringen holdes 1.–3. juli 2026 og 01.09.2026.";
+        let spans = find_date_expressions(text);
+        let expressions: Vec<&DateExpression> = spans.iter().map(|span| &span.expression).collect();
+
+        assert_eq!(
+            expressions,
+            vec![
+                &DateExpression::DayMonth { day: 1, month: 3 },
+                &DateExpression::IncomeYear(2026),
+                &DateExpression::Range {
+                    start: "2026-07-01".to_string(),
+                    end: "2026-07-03".to_string()
+                },
+                &DateExpression::Date("2026-09-01".to_string()),
+            ]
+        );
+        assert_eq!(&text[spans[0].start..spans[0].end], "1. mars");
+        assert_eq!(&text[spans[2].start..spans[2].end], "1.–3. juli 2026");
+    }
+}
@@ -0,0 +1,220 @@
+use serde::Serialize;
+
+use crate::budget::ExtractionBudget;
+use crate::dates::{amount_value, period_unit, PeriodUnit, PERIOD_UNIT_PATTERN};
+use crate::dates::{date_expression_pattern, parse_date_expression, DateExpression};
+use crate::prefilter::{Literal, LiteralHits};
+use crate::references::clause_end;
+use crate::sections::{section_at, section_end};
+use crate::{snippet_around_match, truncate_chars};
+
+/// "innen", "senest (innen)", "i løpet av", "ikke senere enn" and "(klage)fristen er",
+/// with their nynorsk forms.
+const TRIGGER_PATTERN: &str = r"(?:(?:senest|seinast)\s+)?(?:innen|innan)|senest|seinast|i\s+løpet\s+av|ikke\s+senere\s+enn|ikkje\s+seinare\s+enn|[a-zæøå]*frist(?:en|a)?\s+(?:er|på)";
+
+const MAX_ANCHOR_CHARS: usize = 200;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub(crate) enum DeadlineLimit {
+    /// "innen tre uker", "senest 14 dager fra ..."
+    Period { amount: u32, unit: PeriodUnit },
+    /// "innen 1. juli 2027"
+    Date { date: String },
+    /// "senest 1. mars hvert år", a day of the year without a year.
+    Annual { day: u32, month: u32 },
+}
+
+/// A time limit set by the law. `anchor` is the event a period counts from ("at vedtaket
+/// er mottatt"), when the clause names one.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub(crate) struct Deadline {
+    #[serde(flatten)]
+    pub(crate) limit: DeadlineLimit,
+    pub(crate) anchor: Option<String>,
+    pub(crate) section: Option<String>,
+    pub(crate) matched_snippet: String,
+}
+
+pub(crate) fn extract_deadlines(
+    text: &str,
+    literals: &LiteralHits,
+    budget: &mut ExtractionBudget,
+) -> Vec<Deadline> {
+    if !literals.contains(Literal::Section) {
+        return Vec::new();
+    }
+    let deadline_re = cached_regex!(format!(
+        r"(?i)\b(?:{trigger})\s+(?:(\d{{1,3}}|[a-zæøå]+)\s+({unit})\b|({date}))",
+        trigger = TRIGGER_PATTERN,
+        unit = PERIOD_UNIT_PATTERN,
+        date = date_expression_pattern()
+    ));
+
+    let mut deadlines = Vec::new();
+    for cap in deadline_re.captures_iter(text) {
+        let full_match = cap.get(0).unwrap();
+        let (limit, anchor) = if let Some(amount) = cap.get(1) {
+            let Some(amount) = amount_value(amount.as_str()) else {
+                continue;
+            };
+            let limit = DeadlineLimit::Period {
+                amount,
+                unit: period_unit(&cap[2]),
+            };
+            (limit, period_anchor(text, full_match.end()))
+        } else {
+            let Some(limit) = date_limit(&cap[3]) else {
+                continue;
+            };
+            (limit, None)
+        };
+        if !budget.allows_match(deadlines.len()) {
+            break;
+        }
+
+        deadlines.push(Deadline {
+            limit,
+            anchor,
+            section: section_at(text, full_match.start()),
+            matched_snippet: snippet_around_match(text, full_match.start(), full_match.end()),
+        });
+    }
+
+    deadlines
+}
+
+/// A range is due by its last day; an income year is not a deadline.
+fn date_limit(expression: &str) -> Option<DeadlineLimit> {
+    match parse_date_expression(expression)? {
+        DateExpression::Date(date) | DateExpression::Range { end: date, .. } => {
+            Some(DeadlineLimit::Date { date })
+        }
+        DateExpression::DayMonth { day, month } => Some(DeadlineLimit::Annual { day, month }),
+        DateExpression::IncomeYear(_) => None,
+    }
+}
+
+/// The rest of the clause after "etter"/"fra": "innen tre uker etter at vedtaket er
+/// mottatt" counts from "at vedtaket er mottatt".
+fn period_anchor(text: &str, end: usize) -> Option<String> {
+    let clause = &text[end..clause_end(text, end).min(section_end(text, end))];
+    let rest = clause.trim_start();
+    let anchor = ["etter ", "fra ", "frå ", "regnet fra ", "rekna frå "]
+        .iter()
+        .find_map(|prefix| rest.strip_prefix(prefix))?
+        .trim()
+        .trim_end_matches(['.', ',']);
+    (!anchor.is_empty()).then(|| truncate_chars(anchor, MAX_ANCHOR_CHARS))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracts_periods_and_dates_with_section() {
+        let deadlines = extract_deadlines(
+            include_str!("fixtures/deadlines/deadlines.txt"),
+            &LiteralHits::scan(include_str!("fixtures/deadlines/deadlines.txt")),
+            &mut ExtractionBudget::default(),
+        );
+
+        assert_eq!(deadlines.len(), 5);
+
+        assert_eq!(
+            deadlines[0].limit,
+            DeadlineLimit::Period {
+                amount: 3,
+                unit: PeriodUnit::Weeks
+            }
+        );
+        assert_eq!(
+            deadlines[0].anchor.as_deref(),
+            Some("at vedtaket er mottatt")
+        );
+        assert_eq!(deadlines[0].section.as_deref(), Some("§ 7"));
+
+        assert_eq!(
+            deadlines[1].limit,
+            DeadlineLimit::Period {
+                amount: 6,
+                unit: PeriodUnit::Months
+            }
+        );
+        assert_eq!(deadlines[1].anchor, None);
+
+        assert_eq!(
+            deadlines[2].limit,
+            DeadlineLimit::Annual { day: 1, month: 3 }
+        );
+        assert_eq!(deadlines[2].section.as_deref(), Some("§ 8"));
+        assert_eq!(
+            deadlines[3].limit,
+            DeadlineLimit::Date {
+                date: "2027-07-01".to_string()
+            }
+        );
+
+        assert_eq!(
+            deadlines[4].limit,
+            DeadlineLimit::Period {
+                amount: 14,
+                unit: PeriodUnit::Days
+            }
+        );
+        assert_eq!(deadlines[4].anchor.as_deref(), Some("bruddet ble oppdaget"));
+        assert_eq!(deadlines[4].section.as_deref(), Some("§ 9"));
+    }
+
+    #[test]
+    fn accepts_nynorsk_deadlines() {
+        let deadlines = extract_deadlines(
+            "§ 4 Klage Klaga må setjast fram innan tolv veker. Fristen er ein månad.",
+            &LiteralHits::scan(
+                "§ 4 Klage Klaga må setjast fram innan tolv veker. Fristen er ein månad.",
+            ),
+            &mut ExtractionBudget::default(),
+        );
+
+        assert_eq!(deadlines.len(), 2);
+        assert_eq!(
+            deadlines[0].limit,
+            DeadlineLimit::Period {
+                amount: 12,
+                unit: PeriodUnit::Weeks
+            }
+        );
+        assert_eq!(
+            deadlines[1].limit,
+            DeadlineLimit::Period {
+                amount: 1,
+                unit: PeriodUnit::Months
+            }
+        );
+    }
+
+    #[test]
+    fn serializes_limit_with_kind_tag() {
+        let deadline = Deadline {
+            limit: DeadlineLimit::Annual { day: 1, month: 3 },
+            anchor: None,
+            section: None,
+            matched_snippet: String::new(),
+        };
+        let json = serde_json::to_value(&deadline).unwrap();
+
+        assert_eq!(json["kind"], "annual");
+        assert_eq!(json["month"], 3);
+    }
+
+    #[test]
+    fn ignores_unknown_amounts_and_invalid_dates() {
+        assert!(extract_deadlines(
+            "innen rimelig tid og senest 31. februar",
+            &LiteralHits::scan("innen rimelig tid og senest 31. februar"),
+            &mut ExtractionBudget::default()
+        )
+        .is_empty());
+    }
+}
@@ -0,0 +1,221 @@
+use serde::Serialize;
+
+use crate::budget::ExtractionBudget;
+use crate::clamp_to_char_boundary_end;
+use crate::prefilter::{Literal, LiteralHits};
+use crate::sections::{section_at, section_end};
+
+/// "I denne loven menes med:", "I loven her forstås med:", and the nynorsk "I denne lova
+/// meiner ein med:" / "I lova her tyder".
+const LIST_INTRO_PATTERN: &str = r"(?i)\bi\s+(?:denne\s+lov(?:en|a)?|lov(?:en|a)\s+her|dette\s+kap(?:ittelet|itlet|ittel)|denne\s+paragrafen)\s+(?:menes\s+med|forstås\s+med|meiner\s+ein\s+med|tyder)\s*:?";
+
+/// A single inline definition: "Med virksomhet menes i denne loven enhver som ...".
+const INLINE_PATTERN: &str = r"(?i)\bmed\s+«?([^»,:.]{2,60}?)»?\s+(?:menes|meiner\s+ein)\s+(?:i\s+(?:denne\s+lov(?:en|a)?|lov(?:en|a)\s+her)\s+)?([^.]+)";
+
+/// Lettered ("a)") or numbered ("1)") list items.
+const ITEM_MARKER_PATTERN: &str = r"(?:^|[\s:;,])([a-zæøå]|\d{1,2})\)\s+";
+
+const MAX_LIST_CHARS: usize = 6000;
+
+/// A term defined by the law. `label` is the list letter or number the definition appears
+/// under, and `section` the § holding the definitions.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub(crate) struct Definition {
+    pub(crate) term: String,
+    pub(crate) definition: String,
+    pub(crate) label: Option<String>,
+    pub(crate) section: Option<String>,
+}
+
+pub(crate) fn extract_definitions(
+    text: &str,
+    literals: &LiteralHits,
+    budget: &mut ExtractionBudget,
+) -> Vec<Definition> {
+    if !literals.contains(Literal::Section) {
+        return Vec::new();
+    }
+    let intro_re = cached_regex!(LIST_INTRO_PATTERN);
+    let inline_re = cached_regex!(INLINE_PATTERN);
+    let mut definitions = Vec::new();
+
+    for intro in intro_re.find_iter(text) {
+        let limit = clamp_to_char_boundary_end(text, intro.end() + MAX_LIST_CHARS);
+        let end = section_end(text, intro.end()).min(limit);
+        let section = section_at(text, intro.start());
+
+        for (label, item) in list_items(&text[intro.end()..end]) {
+            if let Some((term, definition)) = split_term(item) {
+                if !budget.allows_match(definitions.len()) {
+                    return definitions;
+                }
+                definitions.push(Definition {
+                    term,
+                    definition,
+                    label: Some(label),
+                    section: section.clone(),
+                });
+            }
+        }
+    }
+
+    for cap in inline_re.captures_iter(text) {
+        let term = clean_term(&cap[1]);
+        if definitions.iter().any(|definition| definition.term == term) {
+            continue;
+        }
+        if !budget.allows_match(definitions.len()) {
+            break;
+        }
+        definitions.push(Definition {
+            term,
+            definition: clean_definition(&cap[2]),
+            label: None,
+            section: section_at(text, cap.get(0).unwrap().start()),
+        });
+    }
+
+    definitions
+}
+
+/// Splits a list into its items. Markers must run in sequence (a, b, c or 1, 2, 3), so a
+/// cross-reference such as "bokstav a)" inside a definition does not start a new item.
+fn list_items(list: &str) -> Vec<(String, &str)> {
+    let marker_re = cached_regex!(ITEM_MARKER_PATTERN);
+    let mut markers: Vec<(String, usize, usize)> = Vec::new();
+
+    for cap in marker_re.captures_iter(list) {
+        let label = cap[1].to_string();
+        let expected = match markers.last() {
+            None => label == "a" || label == "1",
+            Some((previous, _, _)) => next_label(previous).as_deref() == Some(label.as_str()),
+        };
+        if expected {
+            let full_match = cap.get(0).unwrap();
+            markers.push((label, full_match.start(), full_match.end()));
+        }
+    }
+
+    markers
+        .iter()
+        .enumerate()
+        .map(|(idx, (label, _, item_start))| {
+            let item_end = markers
+                .get(idx + 1)
+                .map(|(_, next_start, _)| *next_start)
+                .unwrap_or(list.len());
+            (label.clone(), &list[*item_start..item_end])
+        })
+        .collect()
+}
+
+fn next_label(label: &str) -> Option<String> {
+    if let Ok(number) = label.parse::<u32>() {
+        return Some((number + 1).to_string());
+    }
+    let letter = label.chars().next()?;
+    let next = match letter {
+        'z' => 'æ',
+        'æ' => 'ø',
+        'ø' => 'å',
+        _ => char::from_u32(letter as u32 + 1)?,
+    };
+    Some(next.to_string())
+}
+
+/// "tilbyder: enhver som ..." or, without a colon, "tilbyder, enhver som ...".
+fn split_term(item: &str) -> Option<(String, String)> {
+    let (term, definition) = item.split_once(':').or_else(|| item.split_once(','))?;
+    let term = clean_term(term);
+    let definition = clean_definition(definition);
+    (!term.is_empty() && term.split_whitespace().count() <= 6 && !definition.is_empty())
+        .then_some((term, definition))
+}
+
+fn clean_term(term: &str) -> String {
+    term.trim().trim_matches(['«', '»', '"']).trim().to_string()
+}
+
+/// Drops the list punctuation and the "og"/"eller" joining the last two items.
+fn clean_definition(definition: &str) -> String {
+    let mut definition = definition
+        .trim()
+        .trim_end_matches([',', ';', '.'])
+        .trim_end();
+    for joiner in [" og", " eller", " samt"] {
+        if let Some(stripped) = definition.strip_suffix(joiner) {
+            definition = stripped.trim_end_matches([',', ';']).trim_end();
+        }
+    }
+    definition.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracts_lettered_definitions_list() {
+        let definitions = extract_definitions(
+            include_str!("fixtures/definitions/definitions.txt"),
+            &LiteralHits::scan(include_str!("fixtures/definitions/definitions.txt")),
+            &mut ExtractionBudget::default(),
+        );
+        let terms: Vec<&str> = definitions
+            .iter()
+            .map(|definition| definition.term.as_str())
+            .collect();
+
+        assert_eq!(
+            terms,
+            vec![
+                "tilbyder",
+                "mellomledd",
+                "bruker",
+                "tilsynsmyndigheten",
+                "virksomhet"
+            ]
+        );
+        assert_eq!(
+            definitions[0].definition,
+            "enhver som tilbyr en digital tjeneste i Norge"
+        );
+        assert_eq!(definitions[0].label.as_deref(), Some("a"));
+        assert_eq!(definitions[0].section.as_deref(), Some("§ 2"));
+        assert!(definitions[1].definition.ends_with("jf. § 5 første ledd"));
+        assert_eq!(
+            definitions[2].definition,
+            "fysisk eller juridisk person som benytter en tjeneste"
+        );
+        assert_eq!(definitions[3].definition, "den myndighet Kongen utpeker");
+    }
+
+    #[test]
+    fn extracts_inline_definition() {
+        let definitions = extract_definitions(
+            include_str!("fixtures/definitions/definitions.txt"),
+            &LiteralHits::scan(include_str!("fixtures/definitions/definitions.txt")),
+            &mut ExtractionBudget::default(),
+        );
+        let inline = definitions.last().unwrap();
+
+        assert_eq!(inline.term, "virksomhet");
+        assert_eq!(inline.definition, "enhver som driver næring");
+        assert_eq!(inline.label, None);
+        assert_eq!(inline.section.as_deref(), Some("§ 3"));
+    }
+
+    #[test]
+    fn keeps_cross_references_inside_items() {
+        let definitions = extract_definitions("§ 1 Definisjoner I denne lova meiner ein med: 1) verksemd: eining som nemnd i bokstav a) \
+             i forskrifta 2) tilsynet: Datatilsynet", &LiteralHits::scan("§ 1 Definisjoner I denne lova meiner ein med: 1) verksemd: eining som nemnd i bokstav a) \
+             i forskrifta 2) tilsynet: Datatilsynet"), &mut ExtractionBudget::default());
+
+        assert_eq!(definitions.len(), 2);
+        assert_eq!(
+            definitions[0].definition,
+            "eining som nemnd i bokstav a) i forskrifta"
+        );
+        assert_eq!(definitions[1].label.as_deref(), Some("2"));
+    }
+}
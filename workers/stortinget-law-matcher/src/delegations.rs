@@ -0,0 +1,139 @@
+use serde::Serialize;
+
+use crate::budget::ExtractionBudget;
+use crate::prefilter::{Literal, LiteralHits};
+use crate::references::clause_end;
+use crate::sections::section_at;
+use crate::{snippet_around_match, truncate_chars};
+
+/// Who may issue the regulations: the King, a ministry, or a named agency
+/// ("Arbeidstilsynet", "Helsedirektoratet", "Skatteetaten").
+const AUTHORITY_PATTERN: &str = r"Kongen|[Dd]epartementet|[A-ZÆØÅ][a-zæøå]*(?:departementet|direktoratet|tilsynet|etaten|nemnda|kontoret)";
+
+/// "kan gi forskrift(er)", "kan i forskrift fastsette", and the nynorsk "kan gje forskrift",
+/// "kan i forskrift fastsetje".
+const GRANT_PATTERN: &str = r"kan\s+(?:(?:gi|gje)\s+(?:nærmere\s+|nærare\s+|utfyllende\s+|utfyllande\s+)?forskrift(?:er)?|i\s+forskrift\s+(?:gi|gje|fastsette|fastsetje|fastset|bestemme|regulere)|fastsette\s+forskrift(?:er)?)";
+
+const MAX_TOPIC_CHARS: usize = 300;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum AuthorityKind {
+    King,
+    Ministry,
+    Agency,
+}
+
+/// A statutory authorisation to issue regulations. `topic` is the rest of the clause
+/// ("om depositum", "nærmere regler om gjennomføringen"), `section` the § it sits in.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub(crate) struct Delegation {
+    pub(crate) authority: String,
+    pub(crate) authority_kind: AuthorityKind,
+    pub(crate) topic: String,
+    pub(crate) section: Option<String>,
+    pub(crate) matched_snippet: String,
+}
+
+pub(crate) fn extract_delegations(
+    text: &str,
+    literals: &LiteralHits,
+    budget: &mut ExtractionBudget,
+) -> Vec<Delegation> {
+    if !literals.contains(Literal::Forskrift) {
+        return Vec::new();
+    }
+    let grant_re = cached_regex!(format!(r"\b({})\s+{}\b", AUTHORITY_PATTERN, GRANT_PATTERN));
+
+    let mut delegations = Vec::new();
+    for cap in grant_re.captures_iter(text) {
+        if !budget.allows_match(delegations.len()) {
+            break;
+        }
+        let full_match = cap.get(0).unwrap();
+        let authority = cap[1].to_string();
+        let topic_end = clause_end(text, full_match.end());
+        let topic = text[full_match.end()..topic_end]
+            .trim()
+            .trim_end_matches(['.', ',']);
+
+        delegations.push(Delegation {
+            authority_kind: authority_kind(&authority),
+            authority,
+            topic: truncate_chars(topic, MAX_TOPIC_CHARS),
+            section: section_at(text, full_match.start()),
+            matched_snippet: snippet_around_match(text, full_match.start(), full_match.end()),
+        });
+    }
+
+    delegations
+}
+
+fn authority_kind(authority: &str) -> AuthorityKind {
+    if authority == "Kongen" {
+        AuthorityKind::King
+    } else if authority.to_lowercase().ends_with("departementet") {
+        AuthorityKind::Ministry
+    } else {
+        AuthorityKind::Agency
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracts_delegations_with_authority_topic_and_section() {
+        let delegations = extract_delegations(
+            include_str!("fixtures/delegations/delegations.txt"),
+            &LiteralHits::scan(include_str!("fixtures/delegations/delegations.txt")),
+            &mut ExtractionBudget::default(),
+        );
+
+        assert_eq!(delegations.len(), 3);
+
+        assert_eq!(delegations[0].authority, "Kongen");
+        assert_eq!(delegations[0].authority_kind, AuthorityKind::King);
+        assert_eq!(delegations[0].topic, "om depositumets størrelse");
+        assert_eq!(delegations[0].section.as_deref(), Some("§ 3-5"));
+
+        assert_eq!(delegations[1].authority, "Departementet");
+        assert_eq!(delegations[1].authority_kind, AuthorityKind::Ministry);
+        assert_eq!(delegations[1].topic, "nærmere regler om meldeplikten");
+        assert_eq!(delegations[1].section.as_deref(), Some("§ 3-8"));
+
+        assert_eq!(delegations[2].authority, "Arbeidstilsynet");
+        assert_eq!(delegations[2].authority_kind, AuthorityKind::Agency);
+        assert_eq!(delegations[2].section.as_deref(), Some("§ 3-8"));
+    }
+
+    #[test]
+    fn accepts_nynorsk_grants() {
+        let delegations = extract_delegations(
+            "§ 4 Forskrifter Kongen kan gje forskrift om opplæring. \
+             Kunnskapsdepartementet kan i forskrift fastsetje kva fag som skal vere med.",
+            &LiteralHits::scan(
+                "§ 4 Forskrifter Kongen kan gje forskrift om opplæring. \
+             Kunnskapsdepartementet kan i forskrift fastsetje kva fag som skal vere med.",
+            ),
+            &mut ExtractionBudget::default(),
+        );
+
+        assert_eq!(delegations.len(), 2);
+        assert_eq!(delegations[0].topic, "om opplæring");
+        assert_eq!(delegations[1].authority_kind, AuthorityKind::Ministry);
+        assert_eq!(delegations[1].topic, "kva fag som skal vere med");
+        assert_eq!(delegations[1].section.as_deref(), Some("§ 4"));
+    }
+
+    #[test]
+    fn ignores_other_powers() {
+        assert!(extract_delegations(
+            "Departementet kan gi dispensasjon fra kravet.",
+            &LiteralHits::scan("Departementet kan gi dispensasjon fra kravet."),
+            &mut ExtractionBudget::default()
+        )
+        .is_empty());
+    }
+}
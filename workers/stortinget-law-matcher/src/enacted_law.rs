@@ -0,0 +1,288 @@
+use serde::Serialize;
+
+use crate::budget::ExtractionBudget;
+use crate::clamp_to_char_boundary_start;
+use crate::prefilter::LiteralHits;
+use crate::references::{extract_law_references, LawReference, ReferenceRole};
+
+/// Bytes from the start of the text (or segment) searched for the "Lov om ..." heading.
+const HEADING_SEARCH_BYTES: usize = 2000;
+/// Characters kept of a heading that runs on without a structural marker.
+const MAX_HEADING_CHARS: usize = 300;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum EnactedLawKind {
+    NewLaw,
+    Amendment,
+    Repeal,
+    Samlevedtak,
+}
+
+/// The law a target points at: the title as written in the heading and, when it can be
+/// resolved from a citation in the text, its Lovdata ID.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub(crate) struct PrimaryTarget {
+    pub(crate) title: String,
+    pub(crate) id: Option<String>,
+}
+
+/// What the lovvedtak itself enacts, read from its "Lov om ..." heading.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub(crate) struct EnactedLaw {
+    pub(crate) title: String,
+    pub(crate) short_title: Option<String>,
+    pub(crate) kind: EnactedLawKind,
+    pub(crate) primary_target: Option<PrimaryTarget>,
+}
+
+/// Finds the first "Lov om ..." heading near the start of the text and classifies the
+/// decision. The heading runs up to the first structural marker (a roman part number,
+/// "Kapittel 1", "Del I" or a "§"), a sentence end, or `MAX_HEADING_CHARS`.
+pub(crate) fn identify_enacted_law(text: &str, references: &[LawReference]) -> Option<EnactedLaw> {
+    let heading_re = cached_regex!(r"(?:\b[A-Z]\.\s+)?(?:[Vv]edtak\s+til\s+l|L)ov\s+om\s+");
+    let heading_end_re =
+        cached_regex!(r"\s+(?:[IVX]+|Kapittel\s+1|Del\s+I)\s|\s*§|\.\s+[A-ZÆØÅ][a-zæøå]");
+    let lettered_heading_re = cached_regex!(r"\b[A-Z]\.\s+Lov\s+om\s");
+
+    let search = &text[..clamp_to_char_boundary_start(text, HEADING_SEARCH_BYTES)];
+    let rest = &text[heading_re.find(search)?.end()..];
+    let bounded = match rest.char_indices().nth(MAX_HEADING_CHARS) {
+        // Cut a run-on heading at the last word that fits.
+        Some((limit, _)) => {
+            let head = &rest[..limit];
+            &head[..head.rfind(char::is_whitespace).unwrap_or(limit)]
+        }
+        None => rest,
+    };
+    let subject = match heading_end_re.find(bounded) {
+        Some(end) => &bounded[..end.start()],
+        None if bounded.len() == rest.len() => {
+            let bounded = bounded.trim_end();
+            bounded.strip_suffix('.').unwrap_or(bounded)
+        }
+        None => bounded,
+    }
+    .trim();
+    if subject.is_empty() {
+        return None;
+    }
+    let title = format!("Lov om {}", subject);
+    let lowered = subject.to_lowercase();
+
+    let is_collective = lettered_heading_re.find_iter(text).count() >= 2
+        || cached_regex!(
+            r"^endring(?:er|ar)?\s+i\s+(?:enkelte|flere|fleire|visse|ulike|andre)\s+lover",
+        )
+        .is_match(&lowered);
+
+    let (kind, target_title) = if is_collective {
+        (EnactedLawKind::Samlevedtak, None)
+    } else if let Some(rest) =
+        strip_any_prefix(subject, &["endringer i ", "endringar i ", "endring i "])
+    {
+        (EnactedLawKind::Amendment, Some(rest))
+    } else if let Some(rest) = strip_any_prefix(subject, &["oppheving av ", "opphevelse av "]) {
+        (EnactedLawKind::Repeal, Some(rest))
+    } else {
+        (EnactedLawKind::NewLaw, None)
+    };
+
+    let short_title = match kind {
+        EnactedLawKind::NewLaw => parenthesised(subject),
+        _ => None,
+    };
+
+    let primary_target = target_title.map(|target| {
+        let target = strip_suffixes(target);
+        PrimaryTarget {
+            id: resolve_target_id(target, kind, references),
+            title: target.to_string(),
+        }
+    });
+
+    Some(EnactedLaw {
+        title,
+        short_title,
+        kind,
+        primary_target,
+    })
+}
+
+/// Strips the first matching prefix, ignoring ASCII case.
+fn strip_any_prefix<'a>(value: &'a str, prefixes: &[&str]) -> Option<&'a str> {
+    prefixes.iter().find_map(|prefix| {
+        value
+            .get(..prefix.len())
+            .filter(|head| head.eq_ignore_ascii_case(prefix))
+            .map(|_| &value[prefix.len()..])
+    })
+}
+
+fn parenthesised(value: &str) -> Option<String> {
+    let open = value.rfind('(')?;
+    let close = value[open..].find(')')? + open;
+    let inner = value[open + 1..close].trim();
+    (!inner.is_empty()).then(|| inner.to_string())
+}
+
+/// Drops the "mv."/"m.m." tail and any parenthesised short title from a target title.
+fn strip_suffixes(target: &str) -> &str {
+    let target = match target.find('(') {
+        Some(idx) => &target[..idx],
+        None => target,
+    };
+    let target = target.trim().trim_end_matches('.');
+    target
+        .strip_suffix(" mv")
+        .or_else(|| target.strip_suffix(" m.m"))
+        .unwrap_or(target)
+        .trim()
+}
+
+/// A target written as a citation ("oppheving av lov 3. mars 1995 nr. 10") resolves
+/// directly. Otherwise, a single amended/repealed reference in the body is the target.
+fn resolve_target_id(
+    target: &str,
+    kind: EnactedLawKind,
+    references: &[LawReference],
+) -> Option<String> {
+    let literals = LiteralHits::scan(target);
+    if let Some(reference) =
+        extract_law_references(target, &literals, &mut ExtractionBudget::default())
+            .into_iter()
+            .next()
+    {
+        return Some(reference.id);
+    }
+
+    let expected_role = match kind {
+        EnactedLawKind::Repeal => ReferenceRole::Repealed,
+        _ => ReferenceRole::Amended,
+    };
+    let mut candidates = references.iter().filter(|r| r.role == expected_role);
+    match (candidates.next(), candidates.next()) {
+        (Some(only), None) => Some(only.id.clone()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn identify(text: &str) -> EnactedLaw {
+        identify_enacted_law(
+            text,
+            &extract_law_references(
+                text,
+                &LiteralHits::scan(text),
+                &mut ExtractionBudget::default(),
+            ),
+        )
+        .expect("heading should match")
+    }
+
+    #[test]
+    fn classifies_new_law_with_short_title() {
+        let law = identify(include_str!("fixtures/enacted_law/new_law.txt"));
+
+        assert_eq!(law.kind, EnactedLawKind::NewLaw);
+        assert_eq!(
+            law.title,
+            "Lov om vern av personer som varsler om kritikkverdige forhold (varslingsloven)"
+        );
+        assert_eq!(law.short_title.as_deref(), Some("varslingsloven"));
+        assert_eq!(law.primary_target, None);
+    }
+
+    #[test]
+    fn classifies_amendment_and_resolves_target_from_body() {
+        let law = identify(include_str!("fixtures/references/roles.txt"));
+
+        assert_eq!(law.kind, EnactedLawKind::Amendment);
+        assert_eq!(law.title, "Lov om endringer i helseregisterloven mv.");
+        assert_eq!(
+            law.primary_target,
+            Some(PrimaryTarget {
+                title: "helseregisterloven".to_string(),
+                id: Some("LOV-2017-06-16-60".to_string()),
+            })
+        );
+    }
+
+    #[test]
+    fn classifies_repeal_with_cited_target() {
+        let law = identify(
+            "Vedtak til lov om oppheving av lov 3. mars 1995 nr. 10 om helseregistre I Loven oppheves.",
+        );
+
+        assert_eq!(law.kind, EnactedLawKind::Repeal);
+        assert_eq!(
+            law.primary_target.and_then(|target| target.id).as_deref(),
+            Some("LOV-1995-03-03-10")
+        );
+    }
+
+    #[test]
+    fn classifies_collective_decisions_as_samlevedtak() {
+        let lettered = identify(
+            "A. Lov om endringer i skatteloven I § 5 skal lyde: B. Lov om endringer i merverdiavgiftsloven I § 3 skal lyde:",
+        );
+        let plural = identify("Lov om endringer i enkelte lover på finansområdet I I lov ...");
+
+        assert_eq!(lettered.kind, EnactedLawKind::Samlevedtak);
+        assert_eq!(plural.kind, EnactedLawKind::Samlevedtak);
+        assert_eq!(plural.primary_target, None);
+    }
+
+    #[test]
+    fn heading_stops_at_chapter_numbered_section_and_sentence_end() {
+        let law = identify(
+            "Lov om forvaltning av viltlevande marine ressursar § 1-1 Formålet med lova er å sikre \
+             ei berekraftig forvaltning.",
+        );
+        let sentence = identify("Lov om tilskudd til idrett. Loven trer i kraft straks.");
+        let unmarked = identify(&format!("Lov om {}", "statsbudsjettet og ".repeat(200)));
+
+        assert_eq!(
+            law.title,
+            "Lov om forvaltning av viltlevande marine ressursar"
+        );
+        assert_eq!(sentence.title, "Lov om tilskudd til idrett");
+        assert!(unmarked.title.chars().count() <= "Lov om ".len() + MAX_HEADING_CHARS);
+        assert!(unmarked.title.ends_with("statsbudsjettet og"));
+    }
+
+    #[test]
+    fn reads_capitalised_amendment_prefix() {
+        let law = identify("Lov om Endringer i skatteloven I I lov 26. mars 1999 nr. 14 gjøres");
+
+        assert_eq!(law.kind, EnactedLawKind::Amendment);
+        assert_eq!(
+            law.primary_target.map(|target| target.title).as_deref(),
+            Some("skatteloven")
+        );
+    }
+
+    #[test]
+    fn returns_none_without_heading() {
+        let text = include_str!("fixtures/enforcement/no_match.txt");
+        assert_eq!(identify_enacted_law(text, &[]), None);
+    }
+
+    #[test]
+    fn classifies_nynorsk_amendment() {
+        let law = identify(include_str!("fixtures/language/nynorsk.txt"));
+
+        assert_eq!(law.kind, EnactedLawKind::Amendment);
+        assert_eq!(law.title, "Lov om endringar i opplæringslova");
+        assert_eq!(
+            law.primary_target,
+            Some(PrimaryTarget {
+                title: "opplæringslova".to_string(),
+                id: Some("LOV-2023-06-09-30".to_string()),
+            })
+        );
+    }
+}
@@ -0,0 +1,1160 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::ops::Range;
+
+use crate::dates::{
+    amount_value, date_expression_pattern, date_pattern, day_month_pattern, format_iso,
+    is_valid_date, parse_date, parse_date_expression, parse_day_month, parse_iso, period_unit,
+    DateExpression, PeriodUnit, PERIOD_UNIT_PATTERN,
+};
+use crate::prefilter::{Literal, LiteralHits};
+use crate::{
+    clamp_to_char_boundary_end, clamp_to_char_boundary_start, snippet_around_match,
+    ENFORCEMENT_SNIPPET_NONE,
+};
+
+/// Tokens stored in `law_proposals.enforcement_date`; see `Enforcement::legacy_value`.
+const ENFORCEMENT_KONGEN_BESTEMMER: &str = "KONGEN_BESTEMMER";
+const ENFORCEMENT_STRAKS: &str = "STRAKS";
+const ENFORCEMENT_FLERE_DATOER: &str = "FLERE_DATOER";
+const ENFORCEMENT_PARSER_IKKE_FUNNET: &str = "PARSER_IKKE_FUNNET";
+const ENFORCEMENT_PARSER_FEIL: &str = "PARSER_FEIL";
+const ENFORCEMENT_RELATIV: &str = "RELATIV";
+
+/// Version of the `enforcement` object in the match payload. Bump it with any change to
+/// `Enforcement` that an older edge function would misread.
+pub(crate) const ENFORCEMENT_WIRE_VERSION: u8 = 1;
+
+/// Who a clause applies to: sections ("§ 3, § 4 og § 7 a", "§§ 5-1 til 5-3"), "de øvrige
+/// bestemmelsene" (nynorsk "dei andre føresegnene"), a chapter or part, or the whole law.
+const SCOPE_PATTERN: &str = r"§§?\s*\d+(?:-\d+)?(?:\s?[a-z]\b)?(?:\s*(?:,|og|til|–)\s*(?:§\s*)?\d+(?:-\d+)?(?:\s?[a-z]\b)?)*|\b(?:dei?\s+)?(?:øvrige|andre)\s+(?:bestemmelsene|bestemmelser|endringene|endringer|føresegnene|føresegner|endringane|endringar)|\b(?:kapittel|kap\.|del)\s+[IVX\d]+|\blov(?:en|a)\b|\bendring(?:ene|ane)\b";
+/// The event a relative clause counts from: "kunngjøringen", "at loven er sanksjonert",
+/// nynorsk "kunngjeringa", "at lova er kunngjord".
+const ANCHOR_PATTERN: &str = r"kunngj[øe]ring(?:en|a)?|sanksjon(?:en|a)?|at\s+(?:loven|lova|den)\s+(?:er|ble|blir|vart|vert)\s+(?:kunngjort|kunngjord|sanksjonert)";
+/// How far a schedule clause reaches around its in-force phrase: the scope list before it
+/// ("§§ 5-1 til 5-3 og § 7 a skal") and the date or "Kongen bestemmer" after it.
+const SCHEDULE_SCOPE_BYTES: usize = 160;
+const SCHEDULE_WHEN_BYTES: usize = 80;
+/// "trer i kraft", nynorsk "tek til å gjelde".
+const IN_FORCE_PATTERN: &str = r"(?:trer?\s+i\s+kraft|t(?:ek|a)\s+til\s+å\s+gjelde)";
+/// "fra den tid Kongen bestemmer", nynorsk "frå den tid Kongen fastset".
+const KONGEN_PATTERN: &str =
+    r"(?:fr[aå]\s+den\s+tid\s+)?(?:som\s+)?kongen\s+(?:bestemmer|fastset(?:ter)?)";
+
+/// When a law, or part of it, enters into force. Serialized with a `kind` tag; the JSON
+/// Schema in `supabase/functions/shared/enforcement.schema.json` is generated from it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+#[cfg_attr(test, derive(schemars::JsonSchema))]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub(crate) enum Enforcement {
+    #[cfg_attr(test, schemars(extend("x-legacy-value" = "STRAKS")))]
+    Immediate,
+    #[cfg_attr(test, schemars(extend("x-legacy-value" = "KONGEN_BESTEMMER")))]
+    ByRoyalDecree,
+    Date {
+        #[cfg_attr(test, schemars(extend("format" = "date")))]
+        date: String,
+    },
+    /// Different provisions enter into force at different times.
+    #[cfg_attr(test, schemars(extend("x-legacy-value" = "FLERE_DATOER")))]
+    Schedule,
+    /// Counted from promulgation or sanction; the payload's `enforcement_relative` has the details.
+    #[cfg_attr(test, schemars(extend("x-legacy-value" = "RELATIV")))]
+    Relative,
+    #[cfg_attr(test, schemars(extend("x-legacy-value" = "PARSER_IKKE_FUNNET")))]
+    NotFound,
+    #[cfg_attr(test, schemars(extend("x-legacy-value" = "PARSER_FEIL")))]
+    ParserError,
+}
+
+impl Enforcement {
+    pub(crate) fn date(date: String) -> Self {
+        Enforcement::Date { date }
+    }
+
+    /// The ISO date or token stored in `law_proposals.enforcement_date`.
+    pub(crate) fn legacy_value(&self) -> &str {
+        match self {
+            Enforcement::Immediate => ENFORCEMENT_STRAKS,
+            Enforcement::ByRoyalDecree => ENFORCEMENT_KONGEN_BESTEMMER,
+            Enforcement::Date { date } => date,
+            Enforcement::Schedule => ENFORCEMENT_FLERE_DATOER,
+            Enforcement::Relative => ENFORCEMENT_RELATIV,
+            Enforcement::NotFound => ENFORCEMENT_PARSER_IKKE_FUNNET,
+            Enforcement::ParserError => ENFORCEMENT_PARSER_FEIL,
+        }
+    }
+}
+
+/// The versioned top-level `enforcement` object sent to match-and-link-laws.
+#[derive(Debug, Serialize)]
+#[cfg_attr(test, derive(schemars::JsonSchema))]
+#[cfg_attr(test, schemars(title = "Enforcement"))]
+pub(crate) struct EnforcementWire<'a> {
+    #[cfg_attr(test, schemars(extend("const" = 1)))]
+    pub(crate) version: u8,
+    #[serde(flatten)]
+    pub(crate) enforcement: &'a Enforcement,
+}
+
+impl<'a> EnforcementWire<'a> {
+    pub(crate) fn new(enforcement: &'a Enforcement) -> Self {
+        EnforcementWire {
+            version: ENFORCEMENT_WIRE_VERSION,
+            enforcement,
+        }
+    }
+}
+
+/// How `value` was chosen among the candidates.
+pub(crate) const PRIMARY_RULE_NONE: &str = "none";
+pub(crate) const PRIMARY_RULE_SCHEDULE: &str = "schedule";
+pub(crate) const PRIMARY_RULE_PRIORITY: &str = "priority";
+pub(crate) const PRIMARY_RULE_SUB_DECISIONS: &str = "sub_decisions";
+
+/// Candidate sources in the order the priority rule prefers them.
+const SOURCE_PRIORITY: [&str; 6] = [
+    "straks",
+    "kongen",
+    "fixed_date",
+    "schedule",
+    "relative",
+    "multi",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub(crate) struct EnforcementParseResult {
+    pub(crate) value: Enforcement,
+    pub(crate) matched_snippet: String,
+    pub(crate) source: &'static str,
+    pub(crate) primary_rule: &'static str,
+    pub(crate) ambiguous: bool,
+    pub(crate) candidates: Vec<EnforcementCandidate>,
+    pub(crate) schedule: Vec<EnforcementScheduleEntry>,
+    pub(crate) relative: Option<RelativeEnforcement>,
+}
+
+impl EnforcementParseResult {
+    pub(crate) fn new(value: Enforcement, source: &'static str, matched_snippet: String) -> Self {
+        EnforcementParseResult {
+            value,
+            matched_snippet,
+            source,
+            primary_rule: PRIMARY_RULE_NONE,
+            ambiguous: false,
+            candidates: Vec::new(),
+            schedule: Vec::new(),
+            relative: None,
+        }
+    }
+}
+
+/// One clause that could decide entry into force, kept so reviewers can see what the
+/// primary value won over.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub(crate) struct EnforcementCandidate {
+    pub(crate) value: Enforcement,
+    pub(crate) source: &'static str,
+    pub(crate) matched_snippet: String,
+}
+
+/// Which part of the law an enforcement clause applies to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", content = "provisions", rename_all = "snake_case")]
+pub(crate) enum ProvisionScope {
+    WholeLaw,
+    Provisions(Vec<String>),
+    Remaining,
+}
+
+/// One "X trer i kraft Y" clause: a date, immediately, by royal decree or counted from
+/// promulgation or sanction. `relative` holds the anchor and offset of a `Relative` value.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub(crate) struct EnforcementScheduleEntry {
+    pub(crate) scope: ProvisionScope,
+    pub(crate) value: Enforcement,
+    pub(crate) relative: Option<RelativeEnforcement>,
+    pub(crate) matched_snippet: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum EnforcementAnchor {
+    Promulgation,
+    Sanction,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub(crate) enum RelativeOffset {
+    /// "fra kunngjøring"
+    OnAnchor,
+    /// "seks måneder etter kunngjøring"
+    After { amount: u32, unit: PeriodUnit },
+    /// "1. juli året etter at loven er sanksjonert"
+    DayInFollowingYear { day: u32, month: u32 },
+}
+
+/// Entry into force counted from an event whose date is not in the decision text. The
+/// clause is kept as anchor plus offset and resolved once the anchor date is known; the
+/// stored `law_proposals.enforcement_relative` form has no snippet.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct RelativeEnforcement {
+    pub(crate) anchor: EnforcementAnchor,
+    pub(crate) offset: RelativeOffset,
+    #[serde(default)]
+    pub(crate) matched_snippet: String,
+}
+
+impl RelativeEnforcement {
+    /// Resolves the clause against the anchor's ISO date. Month and year offsets keep the
+    /// day of month, clamped to the last day of a shorter month.
+    pub(crate) fn resolve(&self, anchor_date: &str) -> Option<String> {
+        let (year, month, day) = parse_iso(anchor_date)?;
+        let (year, month, day) = match self.offset {
+            RelativeOffset::OnAnchor => (year, month, day),
+            RelativeOffset::After { amount, unit } => match unit {
+                PeriodUnit::Days => add_days(year, month, day, amount),
+                PeriodUnit::Weeks => add_days(year, month, day, amount * 7),
+                PeriodUnit::Months => add_months(year, month, day, amount),
+                PeriodUnit::Years => add_months(year, month, day, amount * 12),
+            },
+            RelativeOffset::DayInFollowingYear { day, month } => (year + 1, month, day),
+        };
+
+        is_valid_date(year, month, day).then(|| format_iso(year, month, day))
+    }
+}
+
+/// When the law takes effect, as opposed to when it enters into force: "gjelder fra og med
+/// inntektsåret 2026", "med virkning fra 1. januar 2026". `retroactive` compares the effect
+/// date with the decision date and is `None` when that date is unknown.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub(crate) struct EffectDate {
+    pub(crate) value: String,
+    pub(crate) matched_snippet: String,
+    pub(crate) source: &'static str,
+    pub(crate) retroactive: Option<bool>,
+}
+
+pub(crate) fn parser_fail_result() -> EnforcementParseResult {
+    EnforcementParseResult::new(
+        Enforcement::ParserError,
+        "parser_fail",
+        ENFORCEMENT_SNIPPET_NONE.to_string(),
+    )
+}
+
+fn parser_no_match_result() -> EnforcementParseResult {
+    EnforcementParseResult::new(
+        Enforcement::NotFound,
+        "none",
+        ENFORCEMENT_SNIPPET_NONE.to_string(),
+    )
+}
+
+fn candidate_with_match(
+    value: Enforcement,
+    source: &'static str,
+    text: &str,
+    m: regex::Match<'_>,
+) -> EnforcementCandidate {
+    EnforcementCandidate {
+        value,
+        source,
+        matched_snippet: snippet_around_match(text, m.start(), m.end()),
+    }
+}
+
+pub(crate) fn extract_enforcement_date(
+    text: &str,
+    literals: &LiteralHits,
+) -> EnforcementParseResult {
+    // Every clause below needs an in-force phrase or "Kongen bestemmer".
+    if !literals.contains(Literal::InForce) && !literals.contains(Literal::Kongen) {
+        return parser_no_match_result();
+    }
+
+    let schedule = extract_enforcement_schedule(text, literals);
+    let relative = extract_relative_enforcement(text);
+    let candidates = collect_candidates(text, &schedule, relative.as_ref());
+
+    let mut result = choose_primary(&schedule, &candidates);
+    if result.source == "relative" {
+        result.relative = relative;
+    }
+    // Competing values only count as ambiguous when the priority rule had to pick one;
+    // a schedule already says which provision gets which date.
+    result.ambiguous = result.primary_rule == PRIMARY_RULE_PRIORITY
+        && candidates
+            .iter()
+            .any(|candidate| candidate.value != result.value);
+    result.candidates = candidates;
+    result.schedule = schedule;
+    result
+}
+
+/// Every clause that could decide entry into force, deduplicated by value and snippet.
+fn collect_candidates(
+    text: &str,
+    schedule: &[EnforcementScheduleEntry],
+    relative: Option<&RelativeEnforcement>,
+) -> Vec<EnforcementCandidate> {
+    let mut candidates = Vec::new();
+
+    let straks_re = cached_regex!(format!(r"(?i){}\s+straks", IN_FORCE_PATTERN));
+    for m in straks_re.find_iter(text) {
+        candidates.push(candidate_with_match(
+            Enforcement::Immediate,
+            "straks",
+            text,
+            m,
+        ));
+    }
+
+    let kongen_re = cached_regex!(format!("(?i){}", KONGEN_PATTERN));
+    for m in kongen_re.find_iter(text) {
+        candidates.push(candidate_with_match(
+            Enforcement::ByRoyalDecree,
+            "kongen",
+            text,
+            m,
+        ));
+    }
+
+    let fixed_date_re = cached_regex!(format!(r"(?i){}\s+({})", IN_FORCE_PATTERN, date_pattern()));
+    for cap in fixed_date_re.captures_iter(text) {
+        let Some(full_match) = cap.get(0) else {
+            continue;
+        };
+
+        // Rejects invalid dates such as "31. februar 2027"
+        let Some(date) = parse_date(&cap[1]) else {
+            continue;
+        };
+        candidates.push(candidate_with_match(
+            Enforcement::date(date),
+            "fixed_date",
+            text,
+            full_match,
+        ));
+    }
+
+    if let Some(relative) = relative {
+        candidates.push(EnforcementCandidate {
+            value: Enforcement::Relative,
+            source: "relative",
+            matched_snippet: relative.matched_snippet.clone(),
+        });
+    }
+
+    // Schedule clauses repeat the matches above; they only add clauses the single-value
+    // patterns miss, such as "trer i kraft fra 1. januar 2027".
+    for entry in schedule {
+        if candidates
+            .iter()
+            .all(|candidate| candidate.value != entry.value)
+        {
+            candidates.push(EnforcementCandidate {
+                value: entry.value.clone(),
+                source: "schedule",
+                matched_snippet: entry.matched_snippet.clone(),
+            });
+        }
+    }
+
+    let trer_i_kraft_re = cached_regex!(format!("(?i){}", IN_FORCE_PATTERN));
+    let multi_time_re =
+        cached_regex!(r"(?i)(ulike\s+tider|forskjellige\s+tidspunkt|ulike\s+tidspunkt)");
+
+    // A bare "§" is not a signal: nearly every lovvedtak has sections. Distinct
+    // per-provision dates are caught by the schedule above.
+    if let Some(m) = trer_i_kraft_re.find(text) {
+        if multi_time_re.is_match(text) {
+            candidates.push(candidate_with_match(
+                Enforcement::Schedule,
+                "multi",
+                text,
+                m,
+            ));
+        }
+    }
+
+    let mut seen = HashSet::new();
+    candidates.retain(|candidate| {
+        seen.insert((candidate.value.clone(), candidate.matched_snippet.clone()))
+    });
+    candidates
+}
+
+/// The single backward-compatible value: differing dates in the schedule mean
+/// `FLERE_DATOER`; otherwise the first candidate in `SOURCE_PRIORITY` order.
+fn choose_primary(
+    schedule: &[EnforcementScheduleEntry],
+    candidates: &[EnforcementCandidate],
+) -> EnforcementParseResult {
+    if let Some(first) = schedule.first() {
+        if schedule.iter().any(|entry| entry.value != first.value) {
+            let mut result = EnforcementParseResult::new(
+                Enforcement::Schedule,
+                "schedule",
+                first.matched_snippet.clone(),
+            );
+            result.primary_rule = PRIMARY_RULE_SCHEDULE;
+            return result;
+        }
+    }
+
+    let Some(primary) = SOURCE_PRIORITY.iter().find_map(|source| {
+        candidates
+            .iter()
+            .find(|candidate| candidate.source == *source)
+    }) else {
+        return parser_no_match_result();
+    };
+
+    let mut result = EnforcementParseResult::new(
+        primary.value.clone(),
+        primary.source,
+        primary.matched_snippet.clone(),
+    );
+    result.primary_rule = PRIMARY_RULE_PRIORITY;
+    result
+}
+
+/// Parses entry-into-force clauses and their comma/"og" continuations, e.g.
+/// "§ 3 trer i kraft 1. juli 2026, de øvrige bestemmelsene 1. januar 2027".
+fn extract_enforcement_schedule(
+    text: &str,
+    literals: &LiteralHits,
+) -> Vec<EnforcementScheduleEntry> {
+    let clause_re = cached_regex!(format!(
+        r"(?i)(?:({})\s+)?(?:skal\s+)?{}\s+(?:fr[aå]\s+(?:og\s+med\s+)?)?({})",
+        SCOPE_PATTERN,
+        IN_FORCE_PATTERN,
+        schedule_when_pattern()
+    ));
+    let continuation_re = cached_regex!(format!(
+        r"(?i)^(?:\s*[,;]\s*(?:og\s+)?|\s+og\s+)({})\s+(?:(?:skal\s+)?{}\s+)?(?:fr[aå]\s+(?:og\s+med\s+)?)?({})",
+        SCOPE_PATTERN,
+        IN_FORCE_PATTERN,
+        schedule_when_pattern()
+    ));
+
+    let mut schedule = Vec::new();
+    let mut pos = 0;
+
+    // Every clause has an in-force phrase, so the pattern only runs over a window around
+    // each one rather than being tried at every position of the text.
+    for hit in literals.positions(Literal::InForce) {
+        if hit < pos {
+            continue;
+        }
+        let from =
+            clamp_to_char_boundary_start(text, hit.saturating_sub(SCHEDULE_SCOPE_BYTES)).max(pos);
+        let to = clamp_to_char_boundary_end(text, hit + SCHEDULE_WHEN_BYTES);
+        let Some(cap) = clause_re.captures_at(&text[..to], from) else {
+            continue;
+        };
+        let full_match = cap.get(0).unwrap();
+        let scope = cap.get(1).map(|m| m.as_str());
+        push_schedule_entry(&mut schedule, text, full_match.range(), scope, &cap[2]);
+        pos = full_match.end();
+
+        while let Some(next) = continuation_re.captures(&text[pos..]) {
+            let next_match = next.get(0).unwrap();
+            let range = pos + next_match.start()..pos + next_match.end();
+            push_schedule_entry(&mut schedule, text, range, Some(&next[1]), &next[2]);
+            pos += next_match.end();
+        }
+    }
+
+    schedule
+}
+
+fn push_schedule_entry(
+    schedule: &mut Vec<EnforcementScheduleEntry>,
+    text: &str,
+    range: Range<usize>,
+    scope: Option<&str>,
+    when: &str,
+) {
+    let matched_snippet = snippet_around_match(text, range.start, range.end);
+    let relative = parse_relative_when(when, &matched_snippet);
+    let value = if relative.is_some() {
+        Enforcement::Relative
+    } else {
+        let Some(value) = enforcement_value(when) else {
+            return;
+        };
+        value
+    };
+
+    schedule.push(EnforcementScheduleEntry {
+        scope: provision_scope(scope),
+        value,
+        relative,
+        matched_snippet,
+    });
+}
+
+fn provision_scope(scope: Option<&str>) -> ProvisionScope {
+    let Some(scope) = scope.map(str::trim) else {
+        return ProvisionScope::WholeLaw;
+    };
+    let lowered = scope.to_lowercase();
+
+    if lowered.contains("øvrige") || lowered.contains("andre") {
+        ProvisionScope::Remaining
+    } else if ["loven", "lova", "endringene", "endringane"].contains(&lowered.as_str()) {
+        ProvisionScope::WholeLaw
+    } else if let Some(list) = scope.strip_prefix("§§").or_else(|| scope.strip_prefix('§')) {
+        let split_re = cached_regex!(r"\s*(?:,|\bog\b)\s*");
+        ProvisionScope::Provisions(
+            split_re
+                .split(list)
+                .map(|item| item.trim().trim_start_matches('§').trim())
+                .filter(|item| !item.is_empty())
+                .map(|item| format!("§ {}", item))
+                .collect(),
+        )
+    } else {
+        ProvisionScope::Provisions(vec![scope.to_string()])
+    }
+}
+
+/// What a schedule clause can say about when it takes effect.
+fn schedule_when_pattern() -> String {
+    format!(
+        r"straks|{}|{}|{}",
+        date_pattern(),
+        KONGEN_PATTERN,
+        relative_when_pattern()
+    )
+}
+
+/// "fra kunngjøring", "tre måneder etter kunngjøringen" and "1. juli året etter at loven er
+/// sanksjonert". Parse the match with `parse_relative_when`.
+fn relative_when_pattern() -> String {
+    format!(
+        r"(?:(?:fr[aå]|ved)\s+)?kunngj[øe]ring(?:en|a)?|(?:\d{{1,3}}|[a-zæøå]+)\s+(?:{unit})\s+etter\s+(?:{anchor})|{day_month}\s+(?:kalender)?året\s+etter\s+(?:{anchor})",
+        unit = PERIOD_UNIT_PATTERN,
+        day_month = day_month_pattern(),
+        anchor = ANCHOR_PATTERN
+    )
+}
+
+fn enforcement_value(when: &str) -> Option<Enforcement> {
+    let lowered = when.to_lowercase();
+    if lowered == "straks" {
+        return Some(Enforcement::Immediate);
+    }
+    if lowered.contains("kongen") {
+        return Some(Enforcement::ByRoyalDecree);
+    }
+
+    parse_date(&lowered).map(Enforcement::date)
+}
+
+/// Finds the first effect-date clause. An income or tax year takes effect from 1 January.
+/// `decision_date` is the ISO date Stortinget adopted the decision.
+pub(crate) fn extract_effect_date(text: &str, decision_date: Option<&str>) -> Option<EffectDate> {
+    let effect_re = cached_regex!(format!(
+        r"(?i)(?:gjelder|gjeld|virkning|verknad)\s+(?:fr[aå]\s+og\s+med|fr[aå]|f\.o\.m\.)\s+({})",
+        date_expression_pattern()
+    ));
+
+    let effect = effect_re.captures_iter(text).find_map(|cap| {
+        let full_match = cap.get(0)?;
+        let expression = parse_date_expression(&cap[1])?;
+        let source = match expression {
+            DateExpression::IncomeYear(_) => "income_year",
+            _ => "effect_date",
+        };
+        let value = expression.first_date()?;
+
+        let retroactive = decision_date
+            .filter(|decided| parse_iso(decided).is_some())
+            .map(|decided| value.as_str() < decided);
+
+        Some(EffectDate {
+            retroactive,
+            value,
+            matched_snippet: snippet_around_match(text, full_match.start(), full_match.end()),
+            source,
+        })
+    });
+    effect
+}
+
+/// Finds "trer i kraft fra kunngjøring", "... tre måneder etter kunngjøringen" and
+/// "... 1. juli året etter at loven er sanksjonert".
+fn extract_relative_enforcement(text: &str) -> Option<RelativeEnforcement> {
+    let relative_re = cached_regex!(format!(
+        r"(?i){}\s+({})",
+        IN_FORCE_PATTERN,
+        relative_when_pattern()
+    ));
+
+    relative_re.captures_iter(text).find_map(|cap| {
+        let full_match = cap.get(0)?;
+        parse_relative_when(
+            &cap[1],
+            &snippet_around_match(text, full_match.start(), full_match.end()),
+        )
+    })
+}
+
+/// Reads a `relative_when_pattern` match as anchor plus offset.
+fn parse_relative_when(when: &str, matched_snippet: &str) -> Option<RelativeEnforcement> {
+    let when_re = cached_regex!(format!(
+        r"(?i)^(?:(?:(?:fr[aå]|ved)\s+)?(kunngj[øe]ring(?:en|a)?)|(\d{{1,3}}|[a-zæøå]+)\s+({unit})\s+etter\s+({anchor})|({day_month})\s+(?:kalender)?året\s+etter\s+({anchor}))$",
+        unit = PERIOD_UNIT_PATTERN,
+        day_month = day_month_pattern(),
+        anchor = ANCHOR_PATTERN
+    ));
+
+    let cap = when_re.captures(when.trim())?;
+    let (anchor, offset) = if let Some(anchor) = cap.get(1) {
+        (anchor.as_str(), RelativeOffset::OnAnchor)
+    } else if let Some(amount) = cap.get(2) {
+        let unit = period_unit(&cap[3]);
+        let amount = amount_value(amount.as_str())?;
+        (&cap[4], RelativeOffset::After { amount, unit })
+    } else {
+        let (day, month) = parse_day_month(&cap[5])?;
+        (&cap[6], RelativeOffset::DayInFollowingYear { day, month })
+    };
+
+    Some(RelativeEnforcement {
+        anchor: if anchor.to_lowercase().contains("sanksjon") {
+            EnforcementAnchor::Sanction
+        } else {
+            EnforcementAnchor::Promulgation
+        },
+        offset,
+        matched_snippet: matched_snippet.to_string(),
+    })
+}
+
+/// The ISO date `months` months after `date`, clamped like month offsets in relative
+/// clauses.
+pub(crate) fn months_after(date: &str, months: u32) -> Option<String> {
+    let (year, month, day) = parse_iso(date)?;
+    let (year, month, day) = add_months(year, month, day, months);
+    Some(format_iso(year, month, day))
+}
+
+fn add_days(mut year: u32, mut month: u32, mut day: u32, days: u32) -> (u32, u32, u32) {
+    for _ in 0..days {
+        day += 1;
+        if !is_valid_date(year, month, day) {
+            day = 1;
+            month += 1;
+            if month > 12 {
+                month = 1;
+                year += 1;
+            }
+        }
+    }
+    (year, month, day)
+}
+
+fn add_months(year: u32, month: u32, day: u32, months: u32) -> (u32, u32, u32) {
+    let total = month - 1 + months;
+    let (year, month) = (year + total / 12, total % 12 + 1);
+    let day = (1..=day)
+        .rev()
+        .find(|candidate| is_valid_date(year, month, *candidate))
+        .unwrap_or(1);
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(value: &str) -> Enforcement {
+        Enforcement::date(value.to_string())
+    }
+
+    #[test]
+    fn extract_enforcement_date_rejects_invalid_dates() {
+        let text = "Loven trer i kraft 31. februar 2027";
+        let result = extract_enforcement_date(text, &LiteralHits::scan(text));
+        // Should fall through to no match since the invalid date is rejected
+        assert_eq!(result.value, Enforcement::NotFound);
+    }
+
+    #[test]
+    fn extract_enforcement_date_from_exact_date_fixture() {
+        let text = include_str!("fixtures/enforcement/exact_date.txt");
+        let result = extract_enforcement_date(text, &LiteralHits::scan(text));
+
+        assert_eq!(result.value, date("2027-01-01"));
+        assert_eq!(result.source, "fixed_date");
+        assert_ne!(result.matched_snippet, ENFORCEMENT_SNIPPET_NONE);
+    }
+
+    #[test]
+    fn extract_enforcement_date_from_straks_fixture() {
+        let text = include_str!("fixtures/enforcement/straks.txt");
+        let result = extract_enforcement_date(text, &LiteralHits::scan(text));
+
+        assert_eq!(result.value, Enforcement::Immediate);
+        assert_eq!(result.source, "straks");
+    }
+
+    #[test]
+    fn extract_enforcement_date_from_kongen_fixture() {
+        let text = include_str!("fixtures/enforcement/kongen.txt");
+        let result = extract_enforcement_date(text, &LiteralHits::scan(text));
+
+        assert_eq!(result.value, Enforcement::ByRoyalDecree);
+        assert_eq!(result.source, "kongen");
+    }
+
+    #[test]
+    fn extract_enforcement_date_from_multi_fixture() {
+        let text = include_str!("fixtures/enforcement/multi.txt");
+        let result = extract_enforcement_date(text, &LiteralHits::scan(text));
+
+        assert_eq!(result.value, Enforcement::Schedule);
+        assert_eq!(result.source, "multi");
+    }
+
+    #[test]
+    fn extract_enforcement_date_from_no_match_fixture() {
+        let text = include_str!("fixtures/enforcement/no_match.txt");
+        let result = extract_enforcement_date(text, &LiteralHits::scan(text));
+
+        assert_eq!(result.value, Enforcement::NotFound);
+        assert_eq!(result.source, "none");
+        assert_eq!(result.matched_snippet, ENFORCEMENT_SNIPPET_NONE);
+    }
+
+    #[test]
+    fn enforcement_priority_straks_over_kongen() {
+        let text = "Loven trer i kraft straks, fra den tid Kongen bestemmer.";
+        let result = extract_enforcement_date(text, &LiteralHits::scan(text));
+
+        assert_eq!(result.value, Enforcement::Immediate);
+    }
+
+    #[test]
+    fn enforcement_priority_kongen_over_fixed_date() {
+        let text = "Loven gjelder fra den tid Kongen bestemmer og trer i kraft 1. januar 2027.";
+        let result = extract_enforcement_date(text, &LiteralHits::scan(text));
+
+        assert_eq!(result.value, Enforcement::ByRoyalDecree);
+    }
+
+    #[test]
+    fn enforcement_priority_fixed_date_over_multi_signal() {
+        let text = "Loven trer i kraft 1. januar 2027. § 2 trer i kraft til ulike tider.";
+        let result = extract_enforcement_date(text, &LiteralHits::scan(text));
+
+        assert_eq!(result.value, date("2027-01-01"));
+    }
+
+    #[test]
+    fn schedule_splits_provisions_and_remaining() {
+        let text = include_str!("fixtures/enforcement/schedule.txt");
+        let result = extract_enforcement_date(text, &LiteralHits::scan(text));
+
+        assert_eq!(result.value, Enforcement::Schedule);
+        assert_eq!(result.source, "schedule");
+        assert_eq!(
+            result
+                .schedule
+                .iter()
+                .map(|entry| (entry.scope.clone(), entry.value.clone()))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    ProvisionScope::Provisions(vec!["§ 3".to_string(), "§ 4".to_string()]),
+                    date("2026-07-01")
+                ),
+                (ProvisionScope::Remaining, date("2027-01-01")),
+                (
+                    ProvisionScope::Provisions(vec!["§ 9".to_string()]),
+                    Enforcement::ByRoyalDecree
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn schedule_with_single_value_keeps_that_value() {
+        let result = extract_enforcement_date(
+            "Loven trer i kraft 1. januar 2027.",
+            &LiteralHits::scan("Loven trer i kraft 1. januar 2027."),
+        );
+
+        assert_eq!(result.value, date("2027-01-01"));
+        assert_eq!(
+            result.schedule,
+            vec![EnforcementScheduleEntry {
+                scope: ProvisionScope::WholeLaw,
+                value: date("2027-01-01"),
+                relative: None,
+                matched_snippet: "Loven trer i kraft 1. januar 2027.".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn section_sign_alone_is_not_a_multi_signal() {
+        let result = extract_enforcement_date(
+            "Endringen i § 3 trer i kraft. Se også § 4.",
+            &LiteralHits::scan("Endringen i § 3 trer i kraft. Se også § 4."),
+        );
+
+        assert_eq!(result.value, Enforcement::NotFound);
+    }
+
+    #[test]
+    fn schedule_scope_serializes_with_kind_tag() {
+        let json =
+            serde_json::to_value(ProvisionScope::Provisions(vec!["§ 3".to_string()])).unwrap();
+
+        assert_eq!(json["kind"], "provisions");
+        assert_eq!(json["provisions"][0], "§ 3");
+    }
+
+    #[test]
+    fn relative_enforcement_after_promulgation() {
+        let text = include_str!("fixtures/enforcement/relative.txt");
+        let result = extract_enforcement_date(text, &LiteralHits::scan(text));
+        let relative = result.relative.unwrap();
+
+        assert_eq!(result.value, Enforcement::Relative);
+        assert_eq!(result.source, "relative");
+        assert_eq!(relative.anchor, EnforcementAnchor::Promulgation);
+        assert_eq!(
+            relative.offset,
+            RelativeOffset::After {
+                amount: 6,
+                unit: PeriodUnit::Months
+            }
+        );
+        assert_eq!(
+            relative.resolve("2026-08-31").as_deref(),
+            Some("2027-02-28")
+        );
+    }
+
+    #[test]
+    fn relative_enforcement_day_in_year_after_sanction() {
+        let result = extract_enforcement_date(
+            "Loven trer i kraft 1. juli året etter at loven er sanksjonert.",
+            &LiteralHits::scan("Loven trer i kraft 1. juli året etter at loven er sanksjonert."),
+        );
+        let relative = result.relative.unwrap();
+
+        assert_eq!(relative.anchor, EnforcementAnchor::Sanction);
+        assert_eq!(
+            relative.offset,
+            RelativeOffset::DayInFollowingYear { day: 1, month: 7 }
+        );
+        assert_eq!(
+            relative.resolve("2026-06-20").as_deref(),
+            Some("2027-07-01")
+        );
+    }
+
+    #[test]
+    fn relative_enforcement_from_promulgation_and_in_days() {
+        let on_anchor = extract_enforcement_date(
+            "Loven trer i kraft fra kunngjøring.",
+            &LiteralHits::scan("Loven trer i kraft fra kunngjøring."),
+        )
+        .relative
+        .unwrap();
+        let in_days = extract_enforcement_date(
+            "Loven trer i kraft 30 dager etter kunngjøringen.",
+            &LiteralHits::scan("Loven trer i kraft 30 dager etter kunngjøringen."),
+        )
+        .relative
+        .unwrap();
+
+        assert_eq!(on_anchor.offset, RelativeOffset::OnAnchor);
+        assert_eq!(
+            on_anchor.resolve("2026-12-15").as_deref(),
+            Some("2026-12-15")
+        );
+        assert_eq!(in_days.resolve("2026-12-15").as_deref(), Some("2027-01-14"));
+    }
+
+    #[test]
+    fn fixed_and_relative_provision_dates_form_a_schedule() {
+        let result = extract_enforcement_date(
+            "§ 1 trer i kraft 1. januar 2027. § 2 trer i kraft tre måneder etter kunngjøring.",
+            &LiteralHits::scan(
+                "§ 1 trer i kraft 1. januar 2027. § 2 trer i kraft tre måneder etter kunngjøring.",
+            ),
+        );
+
+        assert_eq!(result.value, Enforcement::Schedule);
+        assert_eq!(result.primary_rule, PRIMARY_RULE_SCHEDULE);
+        assert_eq!(
+            result
+                .schedule
+                .iter()
+                .map(|entry| (entry.scope.clone(), entry.value.clone()))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    ProvisionScope::Provisions(vec!["§ 1".to_string()]),
+                    date("2027-01-01")
+                ),
+                (
+                    ProvisionScope::Provisions(vec!["§ 2".to_string()]),
+                    Enforcement::Relative
+                ),
+            ]
+        );
+        assert_eq!(result.schedule[0].relative, None);
+        let relative = result.schedule[1].relative.as_ref().unwrap();
+        assert_eq!(relative.anchor, EnforcementAnchor::Promulgation);
+        assert_eq!(
+            relative.offset,
+            RelativeOffset::After {
+                amount: 3,
+                unit: PeriodUnit::Months
+            }
+        );
+    }
+
+    #[test]
+    fn effect_date_from_income_year_is_retroactive() {
+        let text = include_str!("fixtures/enforcement/effect_date.txt");
+        let effect = extract_effect_date(text, Some("2026-06-12")).unwrap();
+
+        assert_eq!(effect.value, "2026-01-01");
+        assert_eq!(effect.source, "income_year");
+        assert_eq!(effect.retroactive, Some(true));
+        assert_eq!(
+            extract_enforcement_date(text, &LiteralHits::scan(text)).value,
+            Enforcement::Immediate
+        );
+    }
+
+    #[test]
+    fn effect_date_with_explicit_date() {
+        let effect = extract_effect_date(
+            "Loven trer i kraft 1. juli 2026 med virkning fra 1. januar 2027.",
+            Some("2026-06-12"),
+        )
+        .unwrap();
+
+        assert_eq!(effect.value, "2027-01-01");
+        assert_eq!(effect.source, "effect_date");
+        assert_eq!(effect.retroactive, Some(false));
+    }
+
+    #[test]
+    fn effect_date_without_decision_date_has_unknown_retroactivity() {
+        let effect = extract_effect_date("Endringene gjelder fra 1. januar 2026.", None).unwrap();
+
+        assert_eq!(effect.retroactive, None);
+        assert_eq!(
+            extract_effect_date("Loven trer i kraft straks.", None),
+            None
+        );
+    }
+
+    #[test]
+    fn competing_candidates_mark_result_ambiguous() {
+        let text = include_str!("fixtures/enforcement/ambiguous.txt");
+        let result = extract_enforcement_date(text, &LiteralHits::scan(text));
+
+        assert_eq!(result.value, Enforcement::Immediate);
+        assert_eq!(result.primary_rule, PRIMARY_RULE_PRIORITY);
+        assert!(result.ambiguous);
+        assert_eq!(
+            result
+                .candidates
+                .iter()
+                .map(|candidate| (candidate.source, candidate.value.clone()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("straks", Enforcement::Immediate),
+                ("kongen", Enforcement::ByRoyalDecree)
+            ]
+        );
+        assert!(result.candidates[1]
+            .matched_snippet
+            .contains("den Kongen bestemmer"));
+    }
+
+    #[test]
+    fn agreeing_candidates_are_not_ambiguous() {
+        let result = extract_enforcement_date(
+            include_str!("fixtures/enforcement/exact_date.txt"),
+            &LiteralHits::scan(include_str!("fixtures/enforcement/exact_date.txt")),
+        );
+
+        assert!(!result.ambiguous);
+        assert_eq!(result.primary_rule, PRIMARY_RULE_PRIORITY);
+    }
+
+    #[test]
+    fn schedule_with_distinct_dates_is_not_ambiguous() {
+        let result = extract_enforcement_date(
+            include_str!("fixtures/enforcement/schedule.txt"),
+            &LiteralHits::scan(include_str!("fixtures/enforcement/schedule.txt")),
+        );
+
+        assert_eq!(result.primary_rule, PRIMARY_RULE_SCHEDULE);
+        assert!(!result.ambiguous);
+        assert_eq!(result.candidates.len(), 3);
+    }
+
+    #[test]
+    fn no_candidates_uses_no_rule() {
+        let result = extract_enforcement_date(
+            include_str!("fixtures/enforcement/no_match.txt"),
+            &LiteralHits::scan(include_str!("fixtures/enforcement/no_match.txt")),
+        );
+
+        assert_eq!(result.primary_rule, PRIMARY_RULE_NONE);
+        assert!(result.candidates.is_empty());
+    }
+
+    #[test]
+    fn enforcement_schema_file_is_up_to_date() {
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../supabase/functions/shared/enforcement.schema.json"
+        );
+        let schema = schemars::schema_for!(EnforcementWire<'static>);
+        let generated = format!("{}\n", serde_json::to_string_pretty(&schema).unwrap());
+
+        if std::env::var_os("UPDATE_ENFORCEMENT_SCHEMA").is_some() {
+            std::fs::write(path, &generated).unwrap();
+        }
+        assert_eq!(
+            std::fs::read_to_string(path).unwrap_or_default(),
+            generated,
+            "enforcement.schema.json is stale; rerun with UPDATE_ENFORCEMENT_SCHEMA=1"
+        );
+    }
+
+    #[test]
+    fn enforcement_wire_form_is_versioned_and_tagged() {
+        let date = date("2027-01-01");
+        let json = serde_json::to_value(EnforcementWire::new(&date)).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!({ "version": 1, "kind": "date", "date": "2027-01-01" })
+        );
+        assert_eq!(
+            serde_json::to_value(EnforcementWire::new(&Enforcement::ByRoyalDecree)).unwrap(),
+            serde_json::json!({ "version": 1, "kind": "by_royal_decree" })
+        );
+    }
+
+    #[test]
+    fn nynorsk_schedule_and_royal_decree() {
+        let text = include_str!("fixtures/language/nynorsk.txt");
+        let result = extract_enforcement_date(text, &LiteralHits::scan(text));
+
+        assert_eq!(result.value, Enforcement::Schedule);
+        assert_eq!(
+            result
+                .schedule
+                .iter()
+                .map(|entry| (entry.scope.clone(), entry.value.clone()))
+                .collect::<Vec<_>>(),
+            vec![
+                (ProvisionScope::WholeLaw, Enforcement::Immediate),
+                (
+                    ProvisionScope::Provisions(vec!["§ 12-3".to_string()]),
+                    Enforcement::ByRoyalDecree
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn nynorsk_single_value_clauses() {
+        assert_eq!(
+            extract_enforcement_date(
+                "Lova tek til å gjelde straks.",
+                &LiteralHits::scan("Lova tek til å gjelde straks.")
+            )
+            .value,
+            Enforcement::Immediate
+        );
+        assert_eq!(
+            extract_enforcement_date(
+                "Lova tek til å gjelde frå den tid Kongen fastset.",
+                &LiteralHits::scan("Lova tek til å gjelde frå den tid Kongen fastset.")
+            )
+            .value,
+            Enforcement::ByRoyalDecree
+        );
+        assert_eq!(
+            extract_enforcement_date(
+                "Lova tek til å gjelde 1. januar 2027.",
+                &LiteralHits::scan("Lova tek til å gjelde 1. januar 2027.")
+            )
+            .value,
+            date("2027-01-01")
+        );
+    }
+
+    #[test]
+    fn nynorsk_relative_and_effect_clauses() {
+        let relative = extract_enforcement_date(
+            "Lova tek til å gjelde seks månader etter kunngjeringa.",
+            &LiteralHits::scan("Lova tek til å gjelde seks månader etter kunngjeringa."),
+        )
+        .relative
+        .unwrap();
+        let effect = extract_effect_date("Endringane gjeld frå og med inntektsåret 2026.", None);
+
+        assert_eq!(relative.anchor, EnforcementAnchor::Promulgation);
+        assert_eq!(
+            relative.offset,
+            RelativeOffset::After {
+                amount: 6,
+                unit: PeriodUnit::Months
+            }
+        );
+        assert_eq!(
+            effect.map(|effect| effect.value).as_deref(),
+            Some("2026-01-01")
+        );
+    }
+
+    #[test]
+    fn accepts_abbreviated_numeric_and_ordinal_dates() {
+        assert_eq!(
+            extract_enforcement_date(
+                "Loven trer i kraft 1 jan. 2027.",
+                &LiteralHits::scan("Loven trer i kraft 1 jan. 2027.")
+            )
+            .value,
+            date("2027-01-01")
+        );
+        assert_eq!(
+            extract_enforcement_date(
+                "Loven trer i kraft 01.07.2026.",
+                &LiteralHits::scan("Loven trer i kraft 01.07.2026.")
+            )
+            .value,
+            date("2026-07-01")
+        );
+        assert_eq!(
+            extract_enforcement_date(
+                "Lova tek til å gjelde fyrste juli 2026.",
+                &LiteralHits::scan("Lova tek til å gjelde fyrste juli 2026.")
+            )
+            .value,
+            date("2026-07-01")
+        );
+    }
+}
@@ -0,0 +1,247 @@
+use serde::Serialize;
+use std::collections::HashSet;
+
+use crate::budget::ExtractionBudget;
+
+/// The last year whose regulations were numbered "nr. number/year"; from 2015 every act is
+/// "year/number".
+const LAST_NUMBER_FIRST_YEAR: u32 = 2014;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum EuActKind {
+    Directive,
+    Regulation,
+    Decision,
+    EeaAnnex,
+}
+
+impl EuActKind {
+    fn celex_type(self) -> Option<char> {
+        match self {
+            EuActKind::Directive => Some('L'),
+            EuActKind::Regulation => Some('R'),
+            EuActKind::Decision => Some('D'),
+            EuActKind::EeaAnnex => None,
+        }
+    }
+
+    fn eli_type(self) -> Option<&'static str> {
+        match self {
+            EuActKind::Directive => Some("dir"),
+            EuActKind::Regulation => Some("reg"),
+            EuActKind::Decision => Some("dec"),
+            EuActKind::EeaAnnex => None,
+        }
+    }
+}
+
+/// An EU legal act or EEA Agreement annex cited by the decision. `id` is the CELEX
+/// number for EU acts and `EEA-ANNEX-<roman>` for annexes, which have no CELEX/ELI form.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub(crate) struct EuActReference {
+    pub(crate) id: String,
+    pub(crate) kind: EuActKind,
+    pub(crate) celex: Option<String>,
+    pub(crate) eli: Option<String>,
+    pub(crate) raw: String,
+}
+
+/// Finds EU directive, regulation and decision citations plus "EØS-avtalen vedlegg"
+/// references, deduplicated and in order of first appearance.
+pub(crate) fn extract_eu_acts(text: &str, budget: &mut ExtractionBudget) -> Vec<EuActReference> {
+    let act_re = cached_regex!(
+        r"(?i)\b[a-zæøå-]*(direktiv|forordning|beslutning)\s+(?:\((EU|EF|EØF)(?:,\s*Euratom)?\)\s+)?(nr\.\s*)?(\d{1,4})/(\d{1,4})(/(?:EU|EF|EØF))?",
+    );
+    let annex_re = cached_regex!(r"(?i)EØS-avtal(?:en|a)\s+vedlegg\s+((?-i:[IVXLC]+))\b");
+
+    let mut found: Vec<(usize, EuActReference)> = Vec::new();
+    let mut seen = HashSet::new();
+
+    for cap in act_re.captures_iter(text) {
+        let has_org_marker = cap.get(2).is_some() || cap.get(6).is_some();
+        if !has_org_marker {
+            continue;
+        }
+
+        let kind = match cap[1].to_lowercase().as_str() {
+            "direktiv" => EuActKind::Directive,
+            "forordning" => EuActKind::Regulation,
+            _ => EuActKind::Decision,
+        };
+        let Some((year, number)) = split_year_and_number(&cap[4], &cap[5], cap.get(3).is_some())
+        else {
+            continue;
+        };
+
+        let celex = format!(
+            "3{}{}{:0>4}",
+            year,
+            kind.celex_type().unwrap_or('L'),
+            number
+        );
+        if !seen.insert(celex.clone()) {
+            continue;
+        }
+        if !budget.allows_match(found.len()) {
+            break;
+        }
+
+        let eli = format!(
+            "http://data.europa.eu/eli/{}/{}/{}/oj",
+            kind.eli_type().unwrap_or("dir"),
+            year,
+            number
+        );
+        let m = cap.get(0).unwrap();
+        found.push((
+            m.start(),
+            EuActReference {
+                id: celex.clone(),
+                kind,
+                celex: Some(celex),
+                eli: Some(eli),
+                raw: m.as_str().to_string(),
+            },
+        ));
+    }
+
+    for cap in annex_re.captures_iter(text) {
+        let id = format!("EEA-ANNEX-{}", &cap[1]);
+        if !seen.insert(id.clone()) {
+            continue;
+        }
+        if !budget.allows_match(found.len()) {
+            break;
+        }
+
+        let m = cap.get(0).unwrap();
+        found.push((
+            m.start(),
+            EuActReference {
+                id,
+                kind: EuActKind::EeaAnnex,
+                celex: None,
+                eli: None,
+                raw: m.as_str().to_string(),
+            },
+        ));
+    }
+
+    found.sort_by_key(|(start, _)| *start);
+    found.into_iter().map(|(_, reference)| reference).collect()
+}
+
+/// EU numbering is "year/number" for directives and for every act since 2015, but older
+/// regulations are cited as "nr. number/year". The citation style decides which part is
+/// tried as the year first, and "number/year" is only read with a year before 2015, so
+/// "forordning (EU) nr. 2019/2088" is the 2088th act of 2019.
+fn split_year_and_number(first: &str, second: &str, has_nr_prefix: bool) -> Option<(u32, u32)> {
+    let year_first = year_and_number(first, second);
+    let number_first =
+        year_and_number(second, first).filter(|(year, _)| *year <= LAST_NUMBER_FIRST_YEAR);
+
+    if has_nr_prefix {
+        number_first.or(year_first)
+    } else {
+        year_first.or(number_first)
+    }
+}
+
+fn year_and_number(year: &str, number: &str) -> Option<(u32, u32)> {
+    let year = normalize_year(year)?;
+    let number = number.parse::<u32>().ok().filter(|n| *n > 0)?;
+    Some((year, number))
+}
+
+/// Two-digit years from 50 are 19xx and the rest 20xx ("93/13/EØF", "02/58/EF").
+fn normalize_year(value: &str) -> Option<u32> {
+    let year = value.parse::<u32>().ok()?;
+    match (value.len(), year) {
+        (2, 50..=99) => Some(1900 + year),
+        (2, _) => Some(2000 + year),
+        (4, 1950..=2099) => Some(year),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(text: &str) -> Vec<String> {
+        extract_eu_acts(text, &mut ExtractionBudget::default())
+            .into_iter()
+            .map(|r| r.id)
+            .collect()
+    }
+
+    #[test]
+    fn extracts_directive_and_regulation_with_celex_and_eli() {
+        let text = include_str!("fixtures/eu_acts/eea_implementation.txt");
+        let acts = extract_eu_acts(text, &mut ExtractionBudget::default());
+
+        assert_eq!(acts[0].celex.as_deref(), Some("32019L1937"));
+        assert_eq!(acts[0].kind, EuActKind::Directive);
+        assert_eq!(
+            acts[0].eli.as_deref(),
+            Some("http://data.europa.eu/eli/dir/2019/1937/oj")
+        );
+        assert_eq!(acts[1].celex.as_deref(), Some("32016R0679"));
+        assert_eq!(
+            acts[1].eli.as_deref(),
+            Some("http://data.europa.eu/eli/reg/2016/679/oj")
+        );
+    }
+
+    #[test]
+    fn extracts_eea_annex_references() {
+        let text = include_str!("fixtures/eu_acts/eea_implementation.txt");
+        let acts = extract_eu_acts(text, &mut ExtractionBudget::default());
+        let annex = acts.iter().find(|a| a.kind == EuActKind::EeaAnnex).unwrap();
+
+        assert_eq!(annex.id, "EEA-ANNEX-XI");
+        assert_eq!(annex.celex, None);
+    }
+
+    #[test]
+    fn handles_legacy_numbering_styles() {
+        assert_eq!(ids("rådsdirektiv 93/13/EØF"), vec!["31993L0013"]);
+        assert_eq!(
+            ids("forordning (EF) nr. 1907/2006 om kjemikalier"),
+            vec!["32006R1907"]
+        );
+        assert_eq!(ids("direktiv 2006/123/EF"), vec!["32006L0123"]);
+        assert_eq!(ids("forordning (EU) nr. 2015/2120"), vec!["32015R2120"]);
+        assert_eq!(
+            ids("gjennomføringsbeslutning (EU) 2021/914"),
+            vec!["32021D0914"]
+        );
+    }
+
+    #[test]
+    fn reads_nr_citations_of_recent_acts_as_year_and_number() {
+        let acts = extract_eu_acts(
+            "forordning (EU) nr. 2019/2088 om bærekraftsopplysninger",
+            &mut ExtractionBudget::default(),
+        );
+
+        assert_eq!(acts[0].celex.as_deref(), Some("32019R2088"));
+        assert_eq!(
+            acts[0].eli.as_deref(),
+            Some("http://data.europa.eu/eli/reg/2019/2088/oj")
+        );
+        assert_eq!(ids("forordning (EF) nr. 2006/2004"), vec!["32004R2006"]);
+    }
+
+    #[test]
+    fn extracts_nynorsk_annex_reference() {
+        assert_eq!(ids("EØS-avtala vedlegg XIII"), vec!["EEA-ANNEX-XIII"]);
+    }
+
+    #[test]
+    fn deduplicates_and_ignores_unqualified_numbers() {
+        let text = "direktiv (EU) 2019/1937 og direktiv (EU) 2019/1937. Se direktiv 3/2020.";
+        assert_eq!(ids(text), vec!["32019L1937"]);
+    }
+}
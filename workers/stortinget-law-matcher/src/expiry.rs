@@ -0,0 +1,129 @@
+use serde::Serialize;
+
+use crate::dates::{date_pattern, parse_date};
+use crate::prefilter::{Literal, LiteralHits};
+use crate::snippet_around_match;
+
+/// The law itself, not a cited one: "Loven", "Lova", "Denne loven". The verb may follow
+/// a joined clause ("Loven trer i kraft straks og gjelder til og med ...").
+const SUBJECT_PATTERN: &str = r"\b(?:lov(?:en|a)|denne\s+lov(?:en|a)?)";
+
+const REPEAL_PATTERN: &str = r"(?:oppheves|(?:vert|blir)\s+oppheva|(?:vert|blir)\s+opphevet)\s+(?:med\s+virkning\s+)?(?:fr[aå]\s+(?:og\s+med\s+)?)?";
+
+const VALID_UNTIL_PATTERN: &str = r"(?:gjelder|gjeld)\s+(?:til\s+og\s+med|fr[ae]m\s+til|til)\s+";
+
+const TEMPORARY_PATTERN: &str =
+    r"(?i)\b(?:midlertidig|mellombels)\s+lov\b|\blov(?:en|a)\s+er\s+(?:midlertidig|mellombels)\b";
+
+pub(crate) const SUNSET_SOURCE_REPEAL: &str = "repeal";
+pub(crate) const SUNSET_SOURCE_VALID_UNTIL: &str = "valid_until";
+
+/// A clause ending the law. `date` is the date as written: the law is repealed on it
+/// (`repeal`, "Loven oppheves 1. juli 2027") or applies through it (`valid_until`,
+/// "gjelder til og med 31. desember 2026").
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub(crate) struct SunsetClause {
+    pub(crate) date: String,
+    pub(crate) source: &'static str,
+    pub(crate) matched_snippet: String,
+}
+
+/// Whether the lovvedtak is a temporary law and when it stops applying. A law is
+/// temporary when it has a sunset clause or calls itself "midlertidig lov"; the latter
+/// may come without a date. `sunset_date` is the earliest clause date.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub(crate) struct LawExpiry {
+    pub(crate) is_temporary: bool,
+    pub(crate) sunset_date: Option<String>,
+    pub(crate) temporary_snippet: Option<String>,
+    pub(crate) sunset_clauses: Vec<SunsetClause>,
+}
+
+pub(crate) fn extract_expiry(text: &str, literals: &LiteralHits) -> LawExpiry {
+    // Every clause names the law itself: "Loven", "Lova", "midlertidig lov".
+    if !literals.contains(Literal::Lov) {
+        return LawExpiry::default();
+    }
+    let clause_re = cached_regex!(format!(
+        r"(?i){}\s+(?:[^.]{{0,60}}?\s+og\s+)?(?:({})|({}))({})",
+        SUBJECT_PATTERN,
+        REPEAL_PATTERN,
+        VALID_UNTIL_PATTERN,
+        date_pattern()
+    ));
+    let temporary_re = cached_regex!(TEMPORARY_PATTERN);
+
+    let sunset_clauses: Vec<SunsetClause> = clause_re
+        .captures_iter(text)
+        .filter_map(|cap| {
+            let full_match = cap.get(0)?;
+            Some(SunsetClause {
+                date: parse_date(&cap[3])?,
+                source: if cap.get(1).is_some() {
+                    SUNSET_SOURCE_REPEAL
+                } else {
+                    SUNSET_SOURCE_VALID_UNTIL
+                },
+                matched_snippet: snippet_around_match(text, full_match.start(), full_match.end()),
+            })
+        })
+        .collect();
+    let temporary_snippet = temporary_re
+        .find(text)
+        .map(|m| snippet_around_match(text, m.start(), m.end()));
+
+    LawExpiry {
+        is_temporary: temporary_snippet.is_some() || !sunset_clauses.is_empty(),
+        sunset_date: sunset_clauses
+            .iter()
+            .map(|clause| clause.date.clone())
+            .min(),
+        temporary_snippet,
+        sunset_clauses,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_temporary_law_with_sunset_clauses() {
+        let expiry = extract_expiry(
+            include_str!("fixtures/expiry/temporary.txt"),
+            &LiteralHits::scan(include_str!("fixtures/expiry/temporary.txt")),
+        );
+
+        assert!(expiry.is_temporary);
+        assert!(expiry.temporary_snippet.is_some());
+        assert_eq!(expiry.sunset_date.as_deref(), Some("2026-12-31"));
+        assert_eq!(expiry.sunset_clauses.len(), 2);
+        assert_eq!(expiry.sunset_clauses[0].source, SUNSET_SOURCE_VALID_UNTIL);
+        assert_eq!(expiry.sunset_clauses[1].source, SUNSET_SOURCE_REPEAL);
+        assert_eq!(expiry.sunset_clauses[1].date, "2027-07-01");
+    }
+
+    #[test]
+    fn detects_nynorsk_repeal() {
+        let expiry = extract_expiry(
+            "Lova tek til å gjelde straks. Lova vert oppheva 1. juli 2027.",
+            &LiteralHits::scan("Lova tek til å gjelde straks. Lova vert oppheva 1. juli 2027."),
+        );
+
+        assert!(expiry.is_temporary);
+        assert_eq!(expiry.sunset_date.as_deref(), Some("2027-07-01"));
+        assert_eq!(expiry.temporary_snippet, None);
+    }
+
+    #[test]
+    fn ignores_cited_law_repeals_and_entry_into_force() {
+        let expiry = extract_expiry(
+            "Loven gjelder fra 1. januar 2026. Fra samme tid oppheves lov 3. mars 1995 nr. 10.",
+            &LiteralHits::scan(
+                "Loven gjelder fra 1. januar 2026. Fra samme tid oppheves lov 3. mars 1995 nr. 10.",
+            ),
+        );
+
+        assert_eq!(expiry, LawExpiry::default());
+    }
+}
@@ -0,0 +1,268 @@
+use std::collections::HashMap;
+use worker::{Bucket, Env, Result, Url};
+
+/// R2 bucket binding holding the raw HTML of fetched pages.
+const BUCKET_BINDING: &str = "HTML_CACHE";
+const TTL_VAR: &str = "HTML_CACHE_TTL_SECONDS";
+/// Lovvedtak pages rarely change once published; a day keeps duplicate webhooks and
+/// reprocessing off stortinget.no while still picking up corrections.
+const DEFAULT_TTL_SECONDS: u64 = 24 * 60 * 60;
+
+const META_ETAG: &str = "etag";
+const META_LAST_MODIFIED: &str = "last-modified";
+const META_FETCHED_AT: &str = "fetched-at";
+const META_TRUNCATED: &str = "truncated";
+
+/// How a request may use the stored copy, chosen with the `x-html-cache` request header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CacheMode {
+    /// Serve the stored copy within the TTL and revalidate it after.
+    Default,
+    /// `refresh`: fetch unconditionally and replace the stored copy.
+    Refresh,
+    /// `stored`: use the stored copy whatever its age, fetching only when there is none.
+    /// For re-extracting after a parser upgrade without going to the network.
+    Stored,
+}
+
+impl CacheMode {
+    pub(crate) fn from_header(value: Option<&str>) -> Self {
+        match value
+            .map(|value| value.trim().to_ascii_lowercase())
+            .as_deref()
+        {
+            Some("refresh") => CacheMode::Refresh,
+            Some("stored") => CacheMode::Stored,
+            _ => CacheMode::Default,
+        }
+    }
+}
+
+/// Validators and age of a stored page, kept as the R2 object's custom metadata.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct CachedPage {
+    pub(crate) etag: Option<String>,
+    pub(crate) last_modified: Option<String>,
+    pub(crate) fetched_at_ms: u64,
+    /// The body was cut at `MAX_BODY_BYTES`; only its start is stored.
+    pub(crate) truncated: bool,
+}
+
+impl CachedPage {
+    fn to_metadata(&self) -> HashMap<String, String> {
+        let mut metadata = HashMap::from([
+            (META_FETCHED_AT.to_string(), self.fetched_at_ms.to_string()),
+            (META_TRUNCATED.to_string(), self.truncated.to_string()),
+        ]);
+        if let Some(etag) = &self.etag {
+            metadata.insert(META_ETAG.to_string(), etag.clone());
+        }
+        if let Some(last_modified) = &self.last_modified {
+            metadata.insert(META_LAST_MODIFIED.to_string(), last_modified.clone());
+        }
+        metadata
+    }
+
+    fn from_metadata(metadata: &HashMap<String, String>) -> Option<Self> {
+        Some(CachedPage {
+            etag: metadata.get(META_ETAG).cloned(),
+            last_modified: metadata.get(META_LAST_MODIFIED).cloned(),
+            fetched_at_ms: metadata.get(META_FETCHED_AT)?.parse().ok()?,
+            truncated: metadata
+                .get(META_TRUNCATED)
+                .is_some_and(|value| value == "true"),
+        })
+    }
+
+    fn has_validators(&self) -> bool {
+        self.etag.is_some() || self.last_modified.is_some()
+    }
+}
+
+/// What to do with a request given its mode and the stored copy, if any.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CacheDecision {
+    UseStored,
+    /// Conditional GET with the stored validators; a 304 keeps the stored copy.
+    Revalidate,
+    Fetch,
+}
+
+pub(crate) fn decide(
+    mode: CacheMode,
+    stored: Option<&CachedPage>,
+    now_ms: u64,
+    ttl_ms: u64,
+) -> CacheDecision {
+    let Some(stored) = stored else {
+        return CacheDecision::Fetch;
+    };
+
+    match mode {
+        CacheMode::Refresh => CacheDecision::Fetch,
+        CacheMode::Stored => CacheDecision::UseStored,
+        CacheMode::Default if now_ms.saturating_sub(stored.fetched_at_ms) < ttl_ms => {
+            CacheDecision::UseStored
+        }
+        CacheMode::Default if stored.has_validators() => CacheDecision::Revalidate,
+        CacheMode::Default => CacheDecision::Fetch,
+    }
+}
+
+/// The object key for a page: host, path and sorted query, without scheme or fragment, so
+/// "http://…/vedtak-045/#a" and "https://…/vedtak-045/" share one entry.
+pub(crate) fn cache_key(url: &str) -> Option<String> {
+    let url = Url::parse(url.trim()).ok()?;
+    let host = url.host_str()?;
+
+    let mut query: Vec<(String, String)> = url.query_pairs().into_owned().collect();
+    query.sort();
+    let query = query
+        .iter()
+        .map(|(name, value)| format!("{}={}", name, value))
+        .collect::<Vec<_>>()
+        .join("&");
+
+    let mut key = format!("html/{}{}", host, url.path());
+    if !query.is_empty() {
+        key.push('?');
+        key.push_str(&query);
+    }
+    Some(key)
+}
+
+/// The optional R2 content cache in front of `fetch_html`. Without the `HTML_CACHE`
+/// binding every request goes to stortinget.no, as before.
+pub(crate) struct HtmlCache {
+    bucket: Bucket,
+    pub(crate) ttl_ms: u64,
+}
+
+impl HtmlCache {
+    pub(crate) fn from_env(env: &Env) -> Option<Self> {
+        let bucket = env.bucket(BUCKET_BINDING).ok()?;
+        let ttl_seconds = env
+            .var(TTL_VAR)
+            .ok()
+            .and_then(|value| value.to_string().trim().parse::<u64>().ok())
+            .unwrap_or(DEFAULT_TTL_SECONDS);
+
+        Some(HtmlCache {
+            bucket,
+            ttl_ms: ttl_seconds * 1000,
+        })
+    }
+
+    pub(crate) async fn load(&self, key: &str) -> Result<Option<(CachedPage, String)>> {
+        let Some(object) = self.bucket.get(key).execute().await? else {
+            return Ok(None);
+        };
+        // Entries without readable metadata are treated as missing and get replaced.
+        let Some(page) = CachedPage::from_metadata(&object.custom_metadata()?) else {
+            return Ok(None);
+        };
+        let Some(body) = object.body() else {
+            return Ok(None);
+        };
+
+        Ok(Some((page, body.text().await?)))
+    }
+
+    pub(crate) async fn store(&self, key: &str, page: &CachedPage, html: &str) -> Result<()> {
+        self.bucket
+            .put(key, html.as_bytes().to_vec())
+            .custom_metadata(page.to_metadata())
+            .execute()
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR_MS: u64 = 60 * 60 * 1000;
+
+    fn page(fetched_at_ms: u64, etag: Option<&str>) -> CachedPage {
+        CachedPage {
+            etag: etag.map(str::to_string),
+            last_modified: None,
+            fetched_at_ms,
+            truncated: false,
+        }
+    }
+
+    #[test]
+    fn normalizes_cache_keys() {
+        assert_eq!(
+            cache_key("https://WWW.Stortinget.no/no/Lovvedtak/vedtak-045/?b=2&a=1#innhold")
+                .as_deref(),
+            Some("html/www.stortinget.no/no/Lovvedtak/vedtak-045/?a=1&b=2")
+        );
+        assert_eq!(
+            cache_key(" http://www.stortinget.no/no/Lovvedtak/vedtak-045/ "),
+            cache_key("https://www.stortinget.no/no/Lovvedtak/vedtak-045/")
+        );
+        assert_eq!(cache_key("not a url"), None);
+    }
+
+    #[test]
+    fn serves_fresh_copies_and_revalidates_stale_ones() {
+        let fresh = page(10 * HOUR_MS, Some("\"v1\""));
+        let now = 12 * HOUR_MS;
+
+        assert_eq!(
+            decide(CacheMode::Default, Some(&fresh), now, 24 * HOUR_MS),
+            CacheDecision::UseStored
+        );
+        assert_eq!(
+            decide(CacheMode::Default, Some(&fresh), now, HOUR_MS),
+            CacheDecision::Revalidate
+        );
+        assert_eq!(
+            decide(CacheMode::Default, Some(&page(0, None)), now, HOUR_MS),
+            CacheDecision::Fetch
+        );
+        assert_eq!(
+            decide(CacheMode::Default, None, now, HOUR_MS),
+            CacheDecision::Fetch
+        );
+    }
+
+    #[test]
+    fn refresh_and_stored_modes_override_the_ttl() {
+        let fresh = page(10 * HOUR_MS, Some("\"v1\""));
+        let stale = page(0, None);
+        let now = 48 * HOUR_MS;
+
+        assert_eq!(
+            decide(CacheMode::Refresh, Some(&fresh), 10 * HOUR_MS, 24 * HOUR_MS),
+            CacheDecision::Fetch
+        );
+        assert_eq!(
+            decide(CacheMode::Stored, Some(&stale), now, HOUR_MS),
+            CacheDecision::UseStored
+        );
+        assert_eq!(
+            decide(CacheMode::Stored, None, now, HOUR_MS),
+            CacheDecision::Fetch
+        );
+        assert_eq!(CacheMode::from_header(Some(" Refresh")), CacheMode::Refresh);
+        assert_eq!(CacheMode::from_header(Some("stored")), CacheMode::Stored);
+        assert_eq!(CacheMode::from_header(None), CacheMode::Default);
+    }
+
+    #[test]
+    fn metadata_round_trips() {
+        let page = CachedPage {
+            etag: Some("\"abc\"".to_string()),
+            last_modified: Some("Tue, 03 Jun 2025 10:00:00 GMT".to_string()),
+            fetched_at_ms: 1_750_000_000_000,
+            truncated: true,
+        };
+
+        assert_eq!(CachedPage::from_metadata(&page.to_metadata()), Some(page));
+        assert_eq!(CachedPage::from_metadata(&HashMap::new()), None);
+    }
+}
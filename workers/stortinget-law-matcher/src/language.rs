@@ -0,0 +1,107 @@
+use serde::Serialize;
+
+/// Words that only occur in one of the two written standards. Shared words ("lov", "skal",
+/// "i") and forms both accept ("kunngjøring" in older nynorsk) are left out.
+const BOKMAL_MARKERS: [&str; 14] = [
+    "ikke",
+    "fra",
+    "loven",
+    "lovens",
+    "endringer",
+    "endringene",
+    "bestemmelser",
+    "bestemmelsene",
+    "følgende",
+    "trer",
+    "disse",
+    "hva",
+    "gjøres",
+    "oppheves",
+];
+const NYNORSK_MARKERS: [&str; 16] = [
+    "ikkje",
+    "frå",
+    "lova",
+    "endringar",
+    "endringane",
+    "føresegner",
+    "føresegnene",
+    "følgjande",
+    "tek",
+    "desse",
+    "kva",
+    "dei",
+    "gjeld",
+    "vert",
+    "fastset",
+    "kunngjering",
+];
+
+/// The written standard of a lovvedtak. Extractors accept both, since a nynorsk decision
+/// still quotes bokmål law titles and vice versa; the detected language is reported so
+/// reviewers know which phrasing the rules matched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum Language {
+    Bokmal,
+    Nynorsk,
+}
+
+/// Counts marker words of each standard. Ties, including texts with no markers at all,
+/// are reported as bokmål, which most decisions are written in.
+pub(crate) fn detect_language(text: &str) -> Language {
+    let mut bokmal = 0;
+    let mut nynorsk = 0;
+    // Reused for every word; markers include non-ASCII letters ("frå"), so ASCII-only
+    // case folding would miss an upper-case "FRÅ".
+    let mut lowered = String::new();
+
+    for word in text
+        .split(|c: char| !c.is_alphabetic())
+        .filter(|word| !word.is_empty())
+    {
+        lowered.clear();
+        lowered.extend(word.chars().flat_map(char::to_lowercase));
+        if BOKMAL_MARKERS.contains(&lowered.as_str()) {
+            bokmal += 1;
+        } else if NYNORSK_MARKERS.contains(&lowered.as_str()) {
+            nynorsk += 1;
+        }
+    }
+
+    if nynorsk > bokmal {
+        Language::Nynorsk
+    } else {
+        Language::Bokmal
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_nynorsk_decision() {
+        let text = include_str!("fixtures/language/nynorsk.txt");
+        assert_eq!(detect_language(text), Language::Nynorsk);
+    }
+
+    #[test]
+    fn detects_bokmal_decision() {
+        let text = include_str!("fixtures/references/roles.txt");
+        assert_eq!(detect_language(text), Language::Bokmal);
+    }
+
+    #[test]
+    fn reads_upper_case_markers() {
+        assert_eq!(
+            detect_language("LOVA GJELD FRÅ 1. JULI."),
+            Language::Nynorsk
+        );
+    }
+
+    #[test]
+    fn defaults_to_bokmal_without_markers() {
+        assert_eq!(detect_language("§ 1 skal lyde: ..."), Language::Bokmal);
+    }
+}
@@ -1,31 +1,81 @@
-use regex::Regex;
+/// Compiles `pattern` on first use and hands out the same `Regex` for the rest of the
+/// isolate's life, so extractors don't rebuild their automata on every request.
+macro_rules! cached_regex {
+    ($pattern:expr $(,)?) => {{
+        static REGEX: std::sync::OnceLock<regex::Regex> = std::sync::OnceLock::new();
+        REGEX.get_or_init(|| regex::Regex::new(&$pattern).unwrap())
+    }};
+}
+
+mod budget;
+mod catalogue;
+mod dates;
+mod deadlines;
+mod definitions;
+mod delegations;
+mod enacted_law;
+mod enforcement;
+mod eu_acts;
+mod expiry;
+mod html_cache;
+mod language;
+mod obligations;
+mod penalties;
+mod prefilter;
+mod references;
+mod repeals;
+mod royal_decree;
+mod sections;
+mod sub_decisions;
+mod transitional;
+
+use budget::{decode_body, ExtractionBudget, Truncation, MAX_BODY_BYTES};
+use catalogue::{validate_references, CatalogueEntry, CatalogueSnapshot, LawCatalogue};
+use deadlines::{extract_deadlines, Deadline};
+use definitions::{extract_definitions, Definition};
+use delegations::{extract_delegations, Delegation};
+use enacted_law::{identify_enacted_law, EnactedLaw};
+use enforcement::{
+    extract_effect_date, extract_enforcement_date, parser_fail_result, EffectDate,
+    EnforcementCandidate, EnforcementParseResult, EnforcementScheduleEntry, EnforcementWire,
+    RelativeEnforcement,
+};
+use eu_acts::{extract_eu_acts, EuActReference};
+use expiry::{extract_expiry, LawExpiry};
+use futures_util::StreamExt;
+use html_cache::{cache_key, decide, CacheDecision, CacheMode, CachedPage, HtmlCache};
+use language::{detect_language, Language};
+use obligations::{extract_obligations, Obligation};
+use penalties::{extract_penalties, Penalty};
+use prefilter::LiteralHits;
+use references::{count_role, extract_law_references, LawReference, ReferenceRole};
+use repeals::{extract_repealed_documents, mark_repealed_references, RepealedDocument};
+use royal_decree::{
+    find_resolution, find_sanctioned_law, resolve_enforcement, resolve_relative, PendingProposal,
+    ResolutionEntry,
+};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::cell::RefCell;
+use std::rc::Rc;
 use std::sync::Once;
 use std::time::Duration;
+use sub_decisions::{combined_enforcement, extract_sub_decisions, whole_decision, SubDecision};
 use tracing::{error, info, warn};
 use tracing_subscriber::layer::SubscriberExt;
 use tracing_web::MakeWebConsoleWriter;
+use transitional::{extract_transitional_provisions, TransitionalProvision};
 use worker::*;
 
 const FUNCTION_NAME: &str = "stortinget-law-matcher";
 const RETRY_DELAYS_MS: [u64; 3] = [0, 250, 750];
 
-const ENFORCEMENT_KONGEN_BESTEMMER: &str = "KONGEN_BESTEMMER";
-const ENFORCEMENT_STRAKS: &str = "STRAKS";
-const ENFORCEMENT_FLERE_DATOER: &str = "FLERE_DATOER";
-const ENFORCEMENT_PARSER_IKKE_FUNNET: &str = "PARSER_IKKE_FUNNET";
-const ENFORCEMENT_PARSER_FEIL: &str = "PARSER_FEIL";
 const ENFORCEMENT_SNIPPET_NONE: &str = "none";
 
-static TRACING_INIT: Once = Once::new();
+const INNHOLD_START: &str = "<!-- INNHOLD -->";
+const INNHOLD_END: &str = "<!-- /INNHOLD -->";
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-struct EnforcementParseResult {
-    value: String,
-    matched_snippet: String,
-    source: &'static str,
-}
+static TRACING_INIT: Once = Once::new();
 
 fn init_tracing() {
     TRACING_INIT.call_once(|| {
@@ -78,32 +128,108 @@ fn response_with_request_id(mut response: Response, request_id: &str) -> Result<
     Ok(response)
 }
 
-fn parser_fail_result() -> EnforcementParseResult {
-    EnforcementParseResult {
-        value: ENFORCEMENT_PARSER_FEIL.to_string(),
-        matched_snippet: ENFORCEMENT_SNIPPET_NONE.to_string(),
-        source: "parser_fail",
-    }
+/// Body posted to the `match-and-link-laws` edge function.
+#[derive(Serialize)]
+struct MatchPayload<'a> {
+    proposal_id: &'a str,
+    language: Option<Language>,
+    extracted_ids: Vec<&'a str>,
+    references: &'a [LawReference],
+    eu_acts: &'a [EuActReference],
+    enacted_law: Option<&'a EnactedLaw>,
+    enforcement: EnforcementWire<'a>,
+    enforcement_schedule: &'a [EnforcementScheduleEntry],
+    enforcement_relative: Option<&'a RelativeEnforcement>,
+    effect_date: Option<&'a EffectDate>,
+    enforcement_primary_rule: &'a str,
+    enforcement_ambiguous: bool,
+    enforcement_candidates: &'a [EnforcementCandidate],
+    enforcement_resolution: Option<&'a ResolutionEntry>,
+    transitional_provisions: &'a [TransitionalProvision],
+    repealed_documents: &'a [RepealedDocument],
+    delegations: &'a [Delegation],
+    definitions: &'a [Definition],
+    obligations: &'a [Obligation],
+    deadlines: &'a [Deadline],
+    penalties: &'a [Penalty],
+    /// Set when a size limit cut the extraction short; `truncation` says which.
+    truncated: bool,
+    truncation: &'a [Truncation],
+    /// Sent as top-level `is_temporary`, `sunset_date`, `temporary_snippet` and
+    /// `sunset_clauses` fields.
+    #[serde(flatten)]
+    expiry: Option<&'a LawExpiry>,
+    sub_decisions: &'a [SubDecision],
 }
 
-fn parser_no_match_result() -> EnforcementParseResult {
-    EnforcementParseResult {
-        value: ENFORCEMENT_PARSER_IKKE_FUNNET.to_string(),
-        matched_snippet: ENFORCEMENT_SNIPPET_NONE.to_string(),
-        source: "none",
+impl<'a> MatchPayload<'a> {
+    /// Reports a source that could not be fetched or read. The edge function records the
+    /// failure without touching the proposal's links.
+    fn parser_failure(proposal_id: &'a str, enforcement: &'a EnforcementParseResult) -> Self {
+        MatchPayload {
+            proposal_id,
+            language: None,
+            extracted_ids: Vec::new(),
+            references: &[],
+            eu_acts: &[],
+            enacted_law: None,
+            enforcement: EnforcementWire::new(&enforcement.value),
+            enforcement_schedule: &[],
+            enforcement_relative: None,
+            effect_date: None,
+            enforcement_primary_rule: enforcement.primary_rule,
+            enforcement_ambiguous: false,
+            enforcement_candidates: &[],
+            enforcement_resolution: None,
+            transitional_provisions: &[],
+            repealed_documents: &[],
+            delegations: &[],
+            definitions: &[],
+            obligations: &[],
+            deadlines: &[],
+            penalties: &[],
+            truncated: false,
+            truncation: &[],
+            expiry: None,
+            sub_decisions: &[],
+        }
     }
-}
 
-fn result_with_match(
-    value: &'static str,
-    source: &'static str,
-    text: &str,
-    m: regex::Match<'_>,
-) -> EnforcementParseResult {
-    EnforcementParseResult {
-        value: value.to_string(),
-        matched_snippet: snippet_around_match(text, m.start(), m.end()),
-        source,
+    /// Reports the date a proposal stored as `KONGEN_BESTEMMER` or `RELATIV` now enters into
+    /// force, read from its ikrafttredelse resolution or from the sanctioned law the relative
+    /// clause counts from. Only the enforcement fields are updated.
+    fn royal_decree_resolution(
+        proposal_id: &'a str,
+        enforcement: &'a EnforcementParseResult,
+        resolution: &'a ResolutionEntry,
+    ) -> Self {
+        MatchPayload {
+            proposal_id,
+            language: None,
+            extracted_ids: Vec::new(),
+            references: &[],
+            eu_acts: &[],
+            enacted_law: None,
+            enforcement: EnforcementWire::new(&enforcement.value),
+            enforcement_schedule: &enforcement.schedule,
+            enforcement_relative: None,
+            effect_date: None,
+            enforcement_primary_rule: enforcement.primary_rule,
+            enforcement_ambiguous: enforcement.ambiguous,
+            enforcement_candidates: &enforcement.candidates,
+            enforcement_resolution: Some(resolution),
+            transitional_provisions: &[],
+            repealed_documents: &[],
+            delegations: &[],
+            definitions: &[],
+            obligations: &[],
+            deadlines: &[],
+            penalties: &[],
+            truncated: false,
+            truncation: &[],
+            expiry: None,
+            sub_decisions: &[],
+        }
     }
 }
 
@@ -116,6 +242,7 @@ struct WebhookPayload {
 struct LawProposal {
     id: String,
     stortinget_link: Option<String>,
+    decision_date: Option<String>,
 }
 
 #[event(fetch)]
@@ -128,6 +255,7 @@ pub async fn main(req: Request, env: Env, _ctx: Context) -> Result<Response> {
 
     let expected = env.secret("WEBHOOK_SHARED_SECRET")?.to_string();
     let got = req.headers().get("x-webhook-secret")?.unwrap_or_default();
+    let cache_mode = CacheMode::from_header(req.headers().get("x-html-cache")?.as_deref());
     if got != expected {
         warn!(
             event = "webhook_secret_mismatch",
@@ -154,6 +282,7 @@ pub async fn main(req: Request, env: Env, _ctx: Context) -> Result<Response> {
 
     let proposal_id = payload.record.id;
     let target_url = payload.record.stortinget_link;
+    let decision_date = payload.record.decision_date;
 
     info!(
         event = "webhook_received",
@@ -170,15 +299,20 @@ pub async fn main(req: Request, env: Env, _ctx: Context) -> Result<Response> {
             request_id = %request_id,
             proposal_id = proposal_id.as_str()
         );
-        return response_with_request_id(
-            Response::ok("Skipped: no stortinget_link")?,
-            &request_id,
-        );
+        return response_with_request_id(Response::ok("Skipped: no stortinget_link")?, &request_id);
     };
 
     // Fetch and parse the text, return early on failure
-    let clean_text = match fetch_clean_text_with_retry(url, &request_id).await {
-        Ok(text) => text,
+    let html_cache = HtmlCache::from_env(&env);
+    let source = match fetch_clean_text_with_retry(
+        url,
+        &request_id,
+        html_cache.as_ref(),
+        cache_mode,
+    )
+    .await
+    {
+        Ok(source) => source,
         Err(fetch_error) => {
             error!(
                 event = "fetch_extract_failed_after_retries",
@@ -188,6 +322,7 @@ pub async fn main(req: Request, env: Env, _ctx: Context) -> Result<Response> {
                 stortinget_link = url,
                 error = ?fetch_error
             );
+            report_parser_failure(&env, &proposal_id, &request_id).await;
             return response_with_request_id(
                 Response::error("Failed to fetch source text", 500)?,
                 &request_id,
@@ -195,15 +330,73 @@ pub async fn main(req: Request, env: Env, _ctx: Context) -> Result<Response> {
         }
     };
 
-    let extracted_ids = extract_law_ids(&clean_text);
-    let enforcement_result = extract_enforcement_date(&clean_text);
+    let mut budget = ExtractionBudget::default();
+    if source.truncated {
+        budget.record(Truncation::Body);
+    }
+    let clean_text = budget.limit_text(source.text);
+
+    let language = detect_language(&clean_text);
+    let literals = LiteralHits::scan(&clean_text);
+    let catalogue = load_law_catalogue(&env, &request_id).await;
+    let mut references = extract_law_references(&clean_text, &literals, &mut budget);
+    if let Some(catalogue) = catalogue.as_ref() {
+        validate_references(&mut references, catalogue);
+    }
+    let repealed_documents = extract_repealed_documents(&clean_text, &literals, &mut budget);
+    mark_repealed_references(&mut references, &repealed_documents);
+    let extracted_ids: Vec<&str> = references.iter().map(|r| r.id.as_str()).collect();
+    let enacted_law = identify_enacted_law(&clean_text, &references);
+    let mut sub_decisions =
+        extract_sub_decisions(&clean_text, &literals, catalogue.as_deref(), &mut budget);
+    let enforcement_result = combined_enforcement(&sub_decisions)
+        .unwrap_or_else(|| extract_enforcement_date(&clean_text, &literals));
+    if sub_decisions.is_empty() {
+        sub_decisions.push(whole_decision(
+            enacted_law.clone(),
+            references.clone(),
+            enforcement_result.clone(),
+        ));
+    }
+    let effect_date = extract_effect_date(&clean_text, decision_date.as_deref());
+    let eu_acts = extract_eu_acts(&clean_text, &mut budget);
+    let transitional_provisions =
+        extract_transitional_provisions(&clean_text, &literals, &mut budget);
+    let expiry = extract_expiry(&clean_text, &literals);
+    let delegations = extract_delegations(&clean_text, &literals, &mut budget);
+    let definitions = extract_definitions(&clean_text, &literals, &mut budget);
+    let obligations = extract_obligations(&clean_text, &literals, &mut budget);
+    let deadlines = extract_deadlines(&clean_text, &literals, &mut budget);
+    let penalties = extract_penalties(&clean_text, &literals, &mut budget);
 
     info!(
         event = "law_ids_extracted",
         function = FUNCTION_NAME,
         request_id = %request_id,
         proposal_id = proposal_id.as_str(),
-        extracted_ids_count = extracted_ids.len()
+        language = ?language,
+        extracted_ids_count = extracted_ids.len(),
+        amended_count = count_role(&references, ReferenceRole::Amended),
+        repealed_count = count_role(&references, ReferenceRole::Repealed),
+        repealed_documents_count = repealed_documents.len(),
+        cited_count = count_role(&references, ReferenceRole::Cited),
+        needs_review_count = references.iter().filter(|r| r.needs_review).count(),
+        eu_acts_count = eu_acts.len(),
+        sub_decisions_count = sub_decisions.len(),
+        transitional_provisions_count = transitional_provisions.len(),
+        is_temporary = expiry.is_temporary,
+        delegations_count = delegations.len(),
+        definitions_count = definitions.len(),
+        obligations_count = obligations.len(),
+        deadlines_count = deadlines.len(),
+        penalties_count = penalties.len(),
+        truncated = budget.truncated(),
+        sunset_date = expiry.sunset_date.as_deref(),
+        enacted_law_kind = ?enacted_law.as_ref().map(|law| law.kind),
+        primary_target_id = ?enacted_law
+            .as_ref()
+            .and_then(|law| law.primary_target.as_ref())
+            .and_then(|target| target.id.as_deref())
     );
 
     info!(
@@ -212,32 +405,58 @@ pub async fn main(req: Request, env: Env, _ctx: Context) -> Result<Response> {
         request_id = %request_id,
         proposal_id = proposal_id.as_str(),
         stortinget_link = url,
-        enforcement_date = enforcement_result.value.as_str(),
+        enforcement_date = enforcement_result.value.legacy_value(),
         enforcement_source = enforcement_result.source,
+        primary_rule = enforcement_result.primary_rule,
+        ambiguous = enforcement_result.ambiguous,
+        candidates_count = enforcement_result.candidates.len(),
+        schedule_entries = enforcement_result.schedule.len(),
+        effect_date = effect_date.as_ref().map(|effect| effect.value.as_str()),
+        retroactive = effect_date.as_ref().and_then(|effect| effect.retroactive),
         match_snippet = enforcement_result.matched_snippet.as_str()
     );
 
     let edge_function_url = env.var("LAW_MATCHER_EDGE_FUNCTION_URL")?.to_string();
     let matcher_secret = env.secret("LAW_MATCHER_WORKER_SECRET")?.to_string();
 
-    match send_to_edge_function(
-        &edge_function_url,
-        &matcher_secret,
-        &proposal_id,
-        &extracted_ids,
-        &enforcement_result.value,
-        &request_id,
-    )
-    .await
-    {
+    let extracted_ids_count = extracted_ids.len();
+    let payload = MatchPayload {
+        proposal_id: &proposal_id,
+        language: Some(language),
+        extracted_ids,
+        references: &references,
+        eu_acts: &eu_acts,
+        enacted_law: enacted_law.as_ref(),
+        enforcement: EnforcementWire::new(&enforcement_result.value),
+        enforcement_schedule: &enforcement_result.schedule,
+        enforcement_relative: enforcement_result.relative.as_ref(),
+        effect_date: effect_date.as_ref(),
+        enforcement_primary_rule: enforcement_result.primary_rule,
+        enforcement_ambiguous: enforcement_result.ambiguous,
+        enforcement_candidates: &enforcement_result.candidates,
+        enforcement_resolution: None,
+        transitional_provisions: &transitional_provisions,
+        repealed_documents: &repealed_documents,
+        delegations: &delegations,
+        definitions: &definitions,
+        obligations: &obligations,
+        deadlines: &deadlines,
+        penalties: &penalties,
+        truncated: budget.truncated(),
+        truncation: budget.truncation(),
+        expiry: Some(&expiry),
+        sub_decisions: &sub_decisions,
+    };
+
+    match send_to_edge_function(&edge_function_url, &matcher_secret, &payload, &request_id).await {
         Ok(_) => {
             info!(
                 event = "laws_linked_successfully",
                 function = FUNCTION_NAME,
                 request_id = %request_id,
                 proposal_id = proposal_id.as_str(),
-                extracted_ids_count = extracted_ids.len(),
-                enforcement_date = enforcement_result.value.as_str()
+                extracted_ids_count = extracted_ids_count,
+                enforcement_date = enforcement_result.value.legacy_value()
             );
             response_with_request_id(Response::ok("Linked laws successfully")?, &request_id)
         }
@@ -254,7 +473,231 @@ pub async fn main(req: Request, env: Env, _ctx: Context) -> Result<Response> {
     }
 }
 
-async fn fetch_clean_text_with_retry(url: &str, request_id: &str) -> Result<String> {
+#[event(scheduled)]
+pub async fn scheduled(_event: ScheduledEvent, env: Env, _ctx: ScheduleContext) {
+    init_tracing();
+    console_error_panic_hook::set_once();
+
+    let request_id = generate_request_id("law-matcher-scheduled");
+    if let Err(e) = recheck_royal_decree_proposals(&env, &request_id).await {
+        error!(
+            event = "royal_decree_recheck_failed",
+            function = FUNCTION_NAME,
+            request_id = %request_id,
+            error = ?e
+        );
+    }
+}
+
+/// Re-checks proposals stored as `KONGEN_BESTEMMER` against the configured Lovtidend
+/// listing and reports the dates of any ikrafttredelse resolution published since.
+/// Proposals stored as `RELATIV` are resolved once the law itself is listed with the date
+/// their clause counts from. The job is off unless both `PENDING_ENFORCEMENT_URL` and
+/// `LOVTIDEND_SOURCE_URL` are set.
+async fn recheck_royal_decree_proposals(env: &Env, request_id: &str) -> Result<()> {
+    let (Some(pending_url), Some(source_url)) = (
+        optional_var(env, "PENDING_ENFORCEMENT_URL"),
+        optional_var(env, "LOVTIDEND_SOURCE_URL"),
+    ) else {
+        info!(
+            event = "royal_decree_recheck_disabled",
+            function = FUNCTION_NAME,
+            request_id = %request_id
+        );
+        return Ok(());
+    };
+
+    let pending_key = optional_secret(env, "PENDING_ENFORCEMENT_API_KEY");
+    let pending: Vec<PendingProposal> = fetch_json(&pending_url, pending_key.as_deref()).await?;
+    if pending.is_empty() {
+        return Ok(());
+    }
+
+    let source_key = optional_secret(env, "LOVTIDEND_API_KEY");
+    let resolutions: Vec<ResolutionEntry> = fetch_json(&source_url, source_key.as_deref()).await?;
+
+    let edge_function_url = env.var("LAW_MATCHER_EDGE_FUNCTION_URL")?.to_string();
+    let matcher_secret = env.secret("LAW_MATCHER_WORKER_SECRET")?.to_string();
+
+    let mut resolved_count = 0;
+    for proposal in &pending {
+        let resolved = match &proposal.enforcement_relative {
+            Some(relative) => {
+                resolve_relative_proposal(proposal, relative, &resolutions, request_id)
+            }
+            None => resolve_royal_decree_proposal(proposal, &resolutions, request_id).await,
+        };
+        let Some((enforcement, resolution)) = resolved else {
+            continue;
+        };
+
+        let payload = MatchPayload::royal_decree_resolution(&proposal.id, &enforcement, resolution);
+        match send_to_edge_function(&edge_function_url, &matcher_secret, &payload, request_id).await
+        {
+            Ok(_) => {
+                resolved_count += 1;
+                info!(
+                    event = "royal_decree_resolved",
+                    function = FUNCTION_NAME,
+                    request_id = %request_id,
+                    proposal_id = proposal.id.as_str(),
+                    resolution_id = resolution.id.as_str(),
+                    enforcement_date = enforcement.value.legacy_value(),
+                    schedule_entries = enforcement.schedule.len()
+                );
+            }
+            Err(e) => {
+                error!(
+                    event = "edge_function_error",
+                    function = FUNCTION_NAME,
+                    request_id = %request_id,
+                    proposal_id = proposal.id.as_str(),
+                    error = ?e
+                );
+            }
+        }
+    }
+
+    info!(
+        event = "royal_decree_recheck_completed",
+        function = FUNCTION_NAME,
+        request_id = %request_id,
+        pending_count = pending.len(),
+        resolutions_count = resolutions.len(),
+        resolved_count = resolved_count
+    );
+
+    Ok(())
+}
+
+/// Reads the dates of the ikrafttredelse resolution listed for a `KONGEN_BESTEMMER`
+/// proposal.
+async fn resolve_royal_decree_proposal<'a>(
+    proposal: &PendingProposal,
+    resolutions: &'a [ResolutionEntry],
+    request_id: &str,
+) -> Option<(EnforcementParseResult, &'a ResolutionEntry)> {
+    let resolution = find_resolution(proposal, resolutions)?;
+
+    let resolution_text = match fetch_html(&resolution.url, request_id).await {
+        Ok(fetched) => strip_html_tags(&fetched.html),
+        Err(fetch_error) => {
+            warn!(
+                event = "royal_decree_fetch_failed",
+                function = FUNCTION_NAME,
+                request_id = %request_id,
+                proposal_id = proposal.id.as_str(),
+                resolution_id = resolution.id.as_str(),
+                error = ?fetch_error
+            );
+            return None;
+        }
+    };
+
+    let Some(enforcement) = resolve_enforcement(&resolution_text) else {
+        warn!(
+            event = "royal_decree_dates_not_found",
+            function = FUNCTION_NAME,
+            request_id = %request_id,
+            proposal_id = proposal.id.as_str(),
+            resolution_id = resolution.id.as_str()
+        );
+        return None;
+    };
+
+    Some((enforcement, resolution))
+}
+
+/// Counts a `RELATIV` proposal's clause from the sanction or publication date of the
+/// listed law.
+fn resolve_relative_proposal<'a>(
+    proposal: &PendingProposal,
+    relative: &RelativeEnforcement,
+    resolutions: &'a [ResolutionEntry],
+    request_id: &str,
+) -> Option<(EnforcementParseResult, &'a ResolutionEntry)> {
+    let law = find_sanctioned_law(proposal, resolutions)?;
+
+    let Some(enforcement) = resolve_relative(relative, law) else {
+        warn!(
+            event = "relative_anchor_not_found",
+            function = FUNCTION_NAME,
+            request_id = %request_id,
+            proposal_id = proposal.id.as_str(),
+            resolution_id = law.id.as_str()
+        );
+        return None;
+    };
+
+    Some((enforcement, law))
+}
+
+fn optional_var(env: &Env, name: &str) -> Option<String> {
+    let value = env.var(name).ok()?.to_string();
+    (!value.trim().is_empty()).then_some(value)
+}
+
+fn optional_secret(env: &Env, name: &str) -> Option<String> {
+    env.secret(name).ok().map(|secret| secret.to_string())
+}
+
+async fn report_parser_failure(env: &Env, proposal_id: &str, request_id: &str) {
+    let failure = parser_fail_result();
+    let payload = MatchPayload::parser_failure(proposal_id, &failure);
+
+    let sent = match (
+        env.var("LAW_MATCHER_EDGE_FUNCTION_URL"),
+        env.secret("LAW_MATCHER_WORKER_SECRET"),
+    ) {
+        (Ok(url), Ok(secret)) => {
+            send_to_edge_function(&url.to_string(), &secret.to_string(), &payload, request_id).await
+        }
+        (Err(e), _) | (_, Err(e)) => Err(e),
+    };
+
+    if let Err(e) = sent {
+        warn!(
+            event = "parser_failure_report_failed",
+            function = FUNCTION_NAME,
+            request_id = %request_id,
+            proposal_id = proposal_id,
+            error = ?e
+        );
+    }
+}
+
+/// HTML read from stortinget.no or the content cache. `truncated` is set when the body was
+/// longer than `MAX_BODY_BYTES` and only its start was kept.
+struct FetchedHtml {
+    html: String,
+    truncated: bool,
+    etag: Option<String>,
+    last_modified: Option<String>,
+}
+
+impl FetchedHtml {
+    fn from_cache(page: CachedPage, html: String) -> Self {
+        FetchedHtml {
+            html,
+            truncated: page.truncated,
+            etag: page.etag,
+            last_modified: page.last_modified,
+        }
+    }
+}
+
+/// The cleaned INNHOLD text, `truncated` when it came from a truncated body.
+struct SourceText {
+    text: String,
+    truncated: bool,
+}
+
+async fn fetch_clean_text_with_retry(
+    url: &str,
+    request_id: &str,
+    cache: Option<&HtmlCache>,
+    cache_mode: CacheMode,
+) -> Result<SourceText> {
     let mut last_error: Option<Error> = None;
 
     for (attempt_idx, delay_ms) in RETRY_DELAYS_MS.iter().copied().enumerate() {
@@ -262,8 +705,16 @@ async fn fetch_clean_text_with_retry(url: &str, request_id: &str) -> Result<Stri
             Delay::from(Duration::from_millis(delay_ms)).await;
         }
 
-        match fetch_clean_text_once(url, request_id).await {
-            Ok(clean_text) => return Ok(clean_text),
+        // A retry follows a failure, possibly caused by a bad stored copy, so it goes to
+        // stortinget.no.
+        let mode = if attempt_idx == 0 {
+            cache_mode
+        } else {
+            CacheMode::Refresh
+        };
+
+        match fetch_clean_text_once(url, request_id, cache, mode).await {
+            Ok(source) => return Ok(source),
             Err(fetch_error) => {
                 warn!(
                     event = "fetch_extract_attempt_failed",
@@ -284,28 +735,162 @@ async fn fetch_clean_text_with_retry(url: &str, request_id: &str) -> Result<Stri
     }))
 }
 
-async fn fetch_clean_text_once(url: &str, request_id: &str) -> Result<String> {
-    let html = fetch_html(url, request_id).await?;
+async fn fetch_clean_text_once(
+    url: &str,
+    request_id: &str,
+    cache: Option<&HtmlCache>,
+    cache_mode: CacheMode,
+) -> Result<SourceText> {
+    let fetched = fetch_html_cached(url, request_id, cache, cache_mode).await?;
 
-    let extracted_section =
-        extract_between_comments(&html, "<!-- INNHOLD -->", "<!-- /INNHOLD -->").ok_or_else(
-            || Error::RustError("Could not find expected INNHOLD section markers".to_string()),
-        )?;
+    let extracted_section = innhold_section(&fetched.html, fetched.truncated).ok_or_else(|| {
+        Error::RustError("Could not find expected INNHOLD section markers".to_string())
+    })?;
 
-    let clean_text = strip_html_tags(&extracted_section);
-    if clean_text.trim().is_empty() {
+    let clean_text = strip_html_tags(extracted_section);
+    if clean_text.is_empty() {
         return Err(Error::RustError(
             "Extracted INNHOLD section is empty after HTML stripping".to_string(),
         ));
     }
 
-    Ok(clean_text)
+    Ok(SourceText {
+        text: clean_text,
+        truncated: fetched.truncated,
+    })
 }
 
-async fn fetch_html(url: &str, request_id: &str) -> Result<String> {
+/// `fetch_html` behind the R2 content cache, when one is bound. The stored copy is used
+/// within the TTL, revalidated with its ETag/Last-Modified after it, and served at any
+/// age when stortinget.no cannot be reached. A failed cache write is logged and ignored.
+async fn fetch_html_cached(
+    url: &str,
+    request_id: &str,
+    cache: Option<&HtmlCache>,
+    mode: CacheMode,
+) -> Result<FetchedHtml> {
+    let (Some(cache), Some(key)) = (cache, cache_key(url)) else {
+        return fetch_html(url, request_id).await;
+    };
+
+    let stored = cache.load(&key).await.unwrap_or_else(|load_error| {
+        warn!(
+            event = "html_cache_read_failed",
+            function = FUNCTION_NAME,
+            request_id = %request_id,
+            cache_key = key.as_str(),
+            error = ?load_error
+        );
+        None
+    });
+    let now_ms = js_sys::Date::now() as u64;
+    let decision = decide(
+        mode,
+        stored.as_ref().map(|(page, _)| page),
+        now_ms,
+        cache.ttl_ms,
+    );
+    info!(
+        event = "html_cache_lookup",
+        function = FUNCTION_NAME,
+        request_id = %request_id,
+        cache_key = key.as_str(),
+        mode = ?mode,
+        decision = ?decision
+    );
+
+    let validators = match decision {
+        CacheDecision::UseStored => {
+            if let Some((page, html)) = stored {
+                return Ok(FetchedHtml::from_cache(page, html));
+            }
+            None
+        }
+        CacheDecision::Revalidate => stored.as_ref().map(|(page, _)| page),
+        CacheDecision::Fetch => None,
+    };
+
+    let fetched = match (
+        fetch_html_if_modified(url, request_id, validators).await,
+        stored,
+    ) {
+        (Ok(Some(fetched)), _) => fetched,
+        (Ok(None), Some((mut page, html))) => {
+            page.fetched_at_ms = now_ms;
+            store_cached_page(cache, &key, &page, &html, request_id).await;
+            return Ok(FetchedHtml::from_cache(page, html));
+        }
+        (Err(fetch_error), Some((page, html))) if mode != CacheMode::Refresh => {
+            warn!(
+                event = "html_cache_stale_fallback",
+                function = FUNCTION_NAME,
+                request_id = %request_id,
+                cache_key = key.as_str(),
+                error = ?fetch_error
+            );
+            return Ok(FetchedHtml::from_cache(page, html));
+        }
+        (Ok(None), None) => {
+            return Err(Error::RustError(format!(
+                "Unexpected 304 Not Modified for {}",
+                url
+            )))
+        }
+        (Err(fetch_error), _) => return Err(fetch_error),
+    };
+
+    let page = CachedPage {
+        etag: fetched.etag.clone(),
+        last_modified: fetched.last_modified.clone(),
+        fetched_at_ms: now_ms,
+        truncated: fetched.truncated,
+    };
+    store_cached_page(cache, &key, &page, &fetched.html, request_id).await;
+    Ok(fetched)
+}
+
+async fn store_cached_page(
+    cache: &HtmlCache,
+    key: &str,
+    page: &CachedPage,
+    html: &str,
+    request_id: &str,
+) {
+    if let Err(store_error) = cache.store(key, page, html).await {
+        warn!(
+            event = "html_cache_write_failed",
+            function = FUNCTION_NAME,
+            request_id = %request_id,
+            cache_key = key,
+            error = ?store_error
+        );
+    }
+}
+
+async fn fetch_html(url: &str, request_id: &str) -> Result<FetchedHtml> {
+    fetch_html_if_modified(url, request_id, None)
+        .await?
+        .ok_or_else(|| Error::RustError(format!("Unexpected 304 Not Modified for {}", url)))
+}
+
+/// GETs the page, conditionally on the stored validators when given. `None` means
+/// 304 Not Modified.
+async fn fetch_html_if_modified(
+    url: &str,
+    request_id: &str,
+    validators: Option<&CachedPage>,
+) -> Result<Option<FetchedHtml>> {
     let headers = Headers::new();
     headers.set("User-Agent", "law-listener/1.0")?;
     headers.set("Accept", "text/html,application/xhtml+xml")?;
+    if let Some(page) = validators {
+        if let Some(etag) = &page.etag {
+            headers.set("If-None-Match", etag)?;
+        }
+        if let Some(last_modified) = &page.last_modified {
+            headers.set("If-Modified-Since", last_modified)?;
+        }
+    }
 
     let mut init = RequestInit::new();
     init.with_method(Method::Get);
@@ -314,82 +899,253 @@ async fn fetch_html(url: &str, request_id: &str) -> Result<String> {
     let req = Request::new_with_init(url, &init)?;
     let mut resp = Fetch::Request(req).send().await?;
 
-    if resp.status_code() >= 400 {
-        let body = resp.text().await.unwrap_or_default();
+    if resp.status_code() == 304 {
+        return Ok(None);
+    }
+
+    if !(200..300).contains(&resp.status_code()) {
+        // The error page is not needed, so its body is never read.
         error!(
             event = "fetch_html_failed",
             function = FUNCTION_NAME,
             request_id = %request_id,
             url = %url,
-            status_code = resp.status_code(),
-            body_length = body.len()
+            status_code = resp.status_code()
+        );
+
+        return Err(Error::RustError(format!(
+            "Fetch error {} for {}",
+            resp.status_code(),
+            url
+        )));
+    }
+
+    let (body, truncated) = read_body_limited(&mut resp, MAX_BODY_BYTES).await?;
+    if truncated {
+        warn!(
+            event = "fetch_html_truncated",
+            function = FUNCTION_NAME,
+            request_id = %request_id,
+            url = %url,
+            max_bytes = MAX_BODY_BYTES
         );
+    }
+
+    Ok(Some(FetchedHtml {
+        html: decode_body(body),
+        truncated,
+        etag: resp.headers().get("ETag")?,
+        last_modified: resp.headers().get("Last-Modified")?,
+    }))
+}
+
+/// Reads the body chunk by chunk and stops after `max_bytes`, so an oversized page is never
+/// held in memory whole. Returns the bytes read and whether the body was cut.
+async fn read_body_limited(resp: &mut Response, max_bytes: usize) -> Result<(Vec<u8>, bool)> {
+    let Ok(mut stream) = resp.stream() else {
+        // Empty and already-buffered bodies are not streamable.
+        let mut body = resp.bytes().await?;
+        let truncated = body.len() > max_bytes;
+        body.truncate(max_bytes);
+        return Ok((body, truncated));
+    };
 
+    let mut body = Vec::new();
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk?;
+        let room = max_bytes - body.len();
+        if chunk.len() > room {
+            body.extend_from_slice(&chunk[..room]);
+            return Ok((body, true));
+        }
+        body.extend_from_slice(&chunk);
+    }
+
+    Ok((body, false))
+}
+
+thread_local! {
+    /// The last catalogue this isolate loaded, reused across requests until its TTL.
+    static LAW_CATALOGUE: RefCell<Option<CatalogueSnapshot>> = const { RefCell::new(None) };
+}
+
+/// Loads the `legal_documents` snapshot used to cross-check cited titles, from the isolate's
+/// copy while it is fresh. The catalogue is optional: without `LAW_CATALOGUE_URL`, or when
+/// it cannot be read and no earlier copy is held, references are sent unvalidated.
+async fn load_law_catalogue(env: &Env, request_id: &str) -> Option<Rc<LawCatalogue>> {
+    let url = optional_var(env, "LAW_CATALOGUE_URL")?;
+    let now_ms = js_sys::Date::now() as u64;
+    let cached = LAW_CATALOGUE.with_borrow(|snapshot| {
+        snapshot
+            .as_ref()
+            .and_then(|snapshot| snapshot.fresh(now_ms))
+    });
+    if cached.is_some() {
+        return cached;
+    }
+
+    let api_key = optional_secret(env, "LAW_CATALOGUE_API_KEY");
+    match fetch_law_catalogue(&url, api_key.as_deref()).await {
+        Ok(catalogue) => {
+            info!(
+                event = "law_catalogue_loaded",
+                function = FUNCTION_NAME,
+                request_id = %request_id,
+                catalogue_size = catalogue.len()
+            );
+            let snapshot = CatalogueSnapshot::new(catalogue, now_ms);
+            let catalogue = snapshot.catalogue();
+            LAW_CATALOGUE.set(Some(snapshot));
+            Some(catalogue)
+        }
+        Err(load_error) => {
+            let stale = LAW_CATALOGUE
+                .with_borrow(|snapshot| snapshot.as_ref().map(CatalogueSnapshot::catalogue));
+            warn!(
+                event = "law_catalogue_unavailable",
+                function = FUNCTION_NAME,
+                request_id = %request_id,
+                using_stale_copy = stale.is_some(),
+                error = ?load_error
+            );
+            stale
+        }
+    }
+}
+
+async fn fetch_law_catalogue(url: &str, api_key: Option<&str>) -> Result<LawCatalogue> {
+    let entries: Vec<CatalogueEntry> = fetch_json(url, api_key).await?;
+    Ok(LawCatalogue::from_entries(entries))
+}
+
+async fn fetch_json<T: DeserializeOwned>(url: &str, api_key: Option<&str>) -> Result<T> {
+    let headers = Headers::new();
+    headers.set("User-Agent", "law-listener/1.0")?;
+    headers.set("Accept", "application/json")?;
+    if let Some(key) = api_key {
+        headers.set("apikey", key)?;
+        headers.set("Authorization", &format!("Bearer {}", key))?;
+    }
+
+    let mut init = RequestInit::new();
+    init.with_method(Method::Get);
+    init.with_headers(headers);
+
+    let req = Request::new_with_init(url, &init)?;
+    let mut resp = Fetch::Request(req).send().await?;
+
+    if resp.status_code() >= 400 {
         return Err(Error::RustError(format!(
-            "Fetch error {} for {} (body length {})",
+            "JSON fetch error {} for {}",
             resp.status_code(),
-            url,
-            body.len()
+            url
         )));
     }
 
-    Ok(resp.text().await.unwrap_or_default())
+    resp.json().await
+}
+
+/// Runs the text extractors of the fetch handler over cleaned text, without the catalogue
+/// lookup or network calls. Used by `benches/extraction.rs`; returns the number of findings
+/// so the work cannot be optimised away.
+#[doc(hidden)]
+pub fn run_extractors(clean_text: &str) -> usize {
+    let mut budget = ExtractionBudget::default();
+    let literals = LiteralHits::scan(clean_text);
+    let mut references = extract_law_references(clean_text, &literals, &mut budget);
+    let repealed_documents = extract_repealed_documents(clean_text, &literals, &mut budget);
+    mark_repealed_references(&mut references, &repealed_documents);
+    let sub_decisions = extract_sub_decisions(clean_text, &literals, None, &mut budget);
+    let enforcement_result = combined_enforcement(&sub_decisions)
+        .unwrap_or_else(|| extract_enforcement_date(clean_text, &literals));
+
+    let language = detect_language(clean_text);
+
+    usize::from(language == Language::Nynorsk)
+        + references.len()
+        + repealed_documents.len()
+        + usize::from(identify_enacted_law(clean_text, &references).is_some())
+        + sub_decisions.len()
+        + enforcement_result.candidates.len()
+        + usize::from(extract_effect_date(clean_text, None).is_some())
+        + extract_eu_acts(clean_text, &mut budget).len()
+        + extract_transitional_provisions(clean_text, &literals, &mut budget).len()
+        + extract_expiry(clean_text, &literals).sunset_clauses.len()
+        + extract_delegations(clean_text, &literals, &mut budget).len()
+        + extract_definitions(clean_text, &literals, &mut budget).len()
+        + extract_obligations(clean_text, &literals, &mut budget).len()
+        + extract_deadlines(clean_text, &literals, &mut budget).len()
+        + extract_penalties(clean_text, &literals, &mut budget).len()
 }
 
-fn extract_between_comments(html: &str, start: &str, end: &str) -> Option<String> {
+fn extract_between_comments<'a>(html: &'a str, start: &str, end: &str) -> Option<&'a str> {
     let start_idx = html.find(start)? + start.len();
     let end_idx = html.find(end)?;
     if end_idx > start_idx {
-        Some(html[start_idx..end_idx].trim().to_string())
+        Some(html[start_idx..end_idx].trim())
     } else {
         None
     }
 }
 
+/// The INNHOLD section of a lovvedtak page. A truncated body can end before the closing
+/// marker; the section then runs to the cut.
+fn innhold_section(html: &str, truncated: bool) -> Option<&str> {
+    extract_between_comments(html, INNHOLD_START, INNHOLD_END).or_else(|| {
+        let start_idx = html.find(INNHOLD_START)? + INNHOLD_START.len();
+        truncated.then(|| html[start_idx..].trim())
+    })
+}
+
+/// Drops tags, turns `&nbsp;` and the escaped line breaks and tabs in Stortinget's HTML (a
+/// literal `\n` or `\t`) into spaces, and collapses whitespace, in one pass over the HTML.
 fn strip_html_tags(html: &str) -> String {
     let mut text = String::with_capacity(html.len());
     let mut inside_tag = false;
+    let mut pending_space = false;
+    let mut chars = html.char_indices();
 
-    for c in html.chars() {
-        match c {
-            '<' => inside_tag = true,
-            '>' => inside_tag = false,
-            _ if !inside_tag => text.push(c),
-            _ => {}
+    while let Some((idx, c)) = chars.next() {
+        if inside_tag {
+            inside_tag = c != '>';
+            continue;
         }
-    }
-
-    let text = text
-        .replace("&nbsp;", " ")
-        .replace("\\r\\n", " ")
-        .replace("\\n", " ")
-        .replace("\\r", " ")
-        .replace("\\t", " ");
 
-    let mut collapsed = String::with_capacity(text.len());
-    let mut last_was_space = false;
-
-    for ch in text.chars() {
-        if ch.is_whitespace() {
-            if !last_was_space {
-                collapsed.push(' ');
-                last_was_space = true;
+        let is_space = match c {
+            '<' => {
+                inside_tag = true;
+                continue;
+            }
+            '>' => continue,
+            '&' if html[idx..].starts_with("&nbsp;") => {
+                chars.nth("nbsp;".len() - 1);
+                true
             }
+            '\\' if matches!(html[idx + 1..].chars().next(), Some('r' | 'n' | 't')) => {
+                chars.next();
+                true
+            }
+            _ => c.is_whitespace(),
+        };
+
+        if is_space {
+            pending_space = !text.is_empty();
         } else {
-            collapsed.push(ch);
-            last_was_space = false;
+            if pending_space {
+                text.push(' ');
+                pending_space = false;
+            }
+            text.push(c);
         }
     }
 
-    collapsed.trim().to_string()
+    text
 }
 
 async fn send_to_edge_function(
     url: &str,
     secret: &str,
-    proposal_id: &str,
-    law_ids: &[String],
-    enforcement_date: &str,
+    payload: &MatchPayload<'_>,
     request_id: &str,
 ) -> Result<()> {
     let headers = Headers::new();
@@ -397,18 +1153,12 @@ async fn send_to_edge_function(
     headers.set("x-worker-secret", secret)?;
     headers.set("x-request-id", request_id)?;
 
-    let body_json = serde_json::json!({
-        "proposal_id": proposal_id,
-        "extracted_ids": law_ids,
-        "enforcement_date": enforcement_date,
-    });
+    let body_json = serde_json::to_string(payload)?;
 
     let mut init = RequestInit::new();
     init.with_method(Method::Post)
         .with_headers(headers)
-        .with_body(Some(wasm_bindgen::JsValue::from_str(
-            &body_json.to_string(),
-        )));
+        .with_body(Some(wasm_bindgen::JsValue::from_str(&body_json)));
 
     let req = Request::new_with_init(url, &init)?;
     let resp = Fetch::Request(req).send().await?;
@@ -418,7 +1168,7 @@ async fn send_to_edge_function(
             event = "edge_function_request_failed",
             function = FUNCTION_NAME,
             request_id = %request_id,
-            proposal_id = proposal_id,
+            proposal_id = payload.proposal_id,
             status_code = resp.status_code()
         );
         return Err(Error::from(format!(
@@ -430,105 +1180,6 @@ async fn send_to_edge_function(
     Ok(())
 }
 
-fn extract_law_ids(text: &str) -> Vec<String> {
-    let re = Regex::new(r"(?i)lov\s+(\d{1,2})\.\s*([a-zæøå]+)\s+(\d{4})\s+nr\.?\s+(\d+)").unwrap();
-    let mut found_ids = HashSet::new();
-
-    for cap in re.captures_iter(&text.to_lowercase()) {
-        let Ok(day_num) = cap[1].parse::<u32>() else {
-            continue;
-        };
-        let day = format!("{:0>2}", day_num);
-        let month_name = &cap[2].to_lowercase();
-        let year = &cap[3];
-        let nr = &cap[4];
-
-        if let Some(month_num_str) = map_norwegian_month(month_name) {
-            let Ok(year_num) = year.parse::<u32>() else {
-                continue;
-            };
-            let Ok(month_num) = month_num_str.parse::<u32>() else {
-                continue;
-            };
-
-            // Validate the date
-            if !is_valid_date(year_num, month_num, day_num) {
-                continue;
-            }
-
-            let law_id = format!("LOV-{}-{}-{}-{}", year, month_num_str, day, nr);
-            found_ids.insert(law_id);
-        }
-    }
-
-    found_ids.into_iter().collect()
-}
-
-fn extract_enforcement_date(text: &str) -> EnforcementParseResult {
-    let straks_re = Regex::new(r"(?i)trer\s+i\s+kraft\s+straks").unwrap();
-    if let Some(m) = straks_re.find(text) {
-        return result_with_match(ENFORCEMENT_STRAKS, "straks", text, m);
-    }
-
-    let kongen_re = Regex::new(r"(?i)(fra\s+den\s+tid\s+)?kongen\s+bestemmer").unwrap();
-    if let Some(m) = kongen_re.find(text) {
-        return result_with_match(ENFORCEMENT_KONGEN_BESTEMMER, "kongen", text, m);
-    }
-
-    let fixed_date_re =
-        Regex::new(r"(?i)trer\s+i\s+kraft\s+(\d{1,2})\.\s*([a-zæøå]+)\s+(\d{4})").unwrap();
-    for cap in fixed_date_re.captures_iter(text) {
-        let Some(full_match) = cap.get(0) else {
-            continue;
-        };
-
-        let Ok(day_num) = cap[1].parse::<u32>() else {
-            continue;
-        };
-        if !(1..=31).contains(&day_num) {
-            continue;
-        }
-
-        let Some(month_num_str) = map_norwegian_month(&cap[2].to_lowercase()) else {
-            continue;
-        };
-
-        let Ok(year_num) = cap[3].parse::<u32>() else {
-            continue;
-        };
-
-        let Ok(month_num) = month_num_str.parse::<u32>() else {
-            continue;
-        };
-
-        // Validate the date (e.g., reject "31. februar 2027")
-        if !is_valid_date(year_num, month_num, day_num) {
-            continue;
-        }
-
-        let iso_date = format!("{}-{}-{:0>2}", year_num, month_num_str, day_num);
-        return EnforcementParseResult {
-            value: iso_date,
-            matched_snippet: snippet_around_match(text, full_match.start(), full_match.end()),
-            source: "fixed_date",
-        };
-    }
-
-    let trer_i_kraft_re = Regex::new(r"(?i)trer\s+i\s+kraft").unwrap();
-    let has_trer_i_kraft = trer_i_kraft_re.find(text);
-    let multi_time_re =
-        Regex::new(r"(?i)(ulike\s+tider|forskjellige\s+tidspunkt|til\s+ulike\s+tider)").unwrap();
-
-    let has_multi_signal = text.contains('§') || multi_time_re.is_match(text);
-    if let Some(m) = has_trer_i_kraft {
-        if has_multi_signal {
-            return result_with_match(ENFORCEMENT_FLERE_DATOER, "multi", text, m);
-        }
-    }
-
-    parser_no_match_result()
-}
-
 fn clamp_to_char_boundary_start(value: &str, mut index: usize) -> usize {
     index = index.min(value.len());
     while index > 0 && !value.is_char_boundary(index) {
@@ -572,49 +1223,10 @@ fn snippet_around_match(text: &str, start: usize, end: usize) -> String {
     }
 }
 
-fn is_valid_date(year: u32, month: u32, day: u32) -> bool {
-    if month < 1 || month > 12 {
-        return false;
-    }
-
-    let days_in_month = match month {
-        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
-        4 | 6 | 9 | 11 => 30,
-        2 => {
-            // Check for leap year
-            if (year % 4 == 0 && year % 100 != 0) || (year % 400 == 0) {
-                29
-            } else {
-                28
-            }
-        }
-        _ => return false,
-    };
-
-    day >= 1 && day <= days_in_month
-}
-
-fn map_norwegian_month(month: &str) -> Option<&'static str> {
-    match month {
-        "januar" => Some("01"),
-        "februar" => Some("02"),
-        "mars" => Some("03"),
-        "april" => Some("04"),
-        "mai" => Some("05"),
-        "juni" => Some("06"),
-        "juli" => Some("07"),
-        "august" => Some("08"),
-        "september" => Some("09"),
-        "oktober" => Some("10"),
-        "november" => Some("11"),
-        "desember" => Some("12"),
-        _ => None,
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use enforcement::Enforcement;
     use std::cell::Cell;
 
     fn run_retry_simulation<T, E, F>(
@@ -639,73 +1251,11 @@ mod tests {
         }
     }
 
-    #[test]
-    fn extract_law_ids_finds_and_deduplicates_ids() {
-        let text = "lov 16. juni 2017 nr. 60 og LOV 16. juni 2017 nr 60";
-        let mut ids = extract_law_ids(text);
-        ids.sort();
-
-        assert_eq!(ids, vec!["LOV-2017-06-16-60".to_string()]);
-    }
-
-    #[test]
-    fn extract_law_ids_ignores_unknown_month() {
-        let text = "lov 12. foo 2024 nr. 1";
-        let ids = extract_law_ids(text);
-        assert!(ids.is_empty());
-    }
-
-    #[test]
-    fn is_valid_date_accepts_valid_dates() {
-        assert!(is_valid_date(2024, 1, 31));  // January 31
-        assert!(is_valid_date(2024, 4, 30));  // April 30
-        assert!(is_valid_date(2024, 2, 29));  // Feb 29 in leap year
-        assert!(is_valid_date(2000, 2, 29));  // Feb 29 in leap year (divisible by 400)
-    }
-
-    #[test]
-    fn is_valid_date_rejects_invalid_dates() {
-        assert!(!is_valid_date(2024, 2, 30)); // Feb 30 doesn't exist
-        assert!(!is_valid_date(2027, 2, 31)); // Feb 31 doesn't exist
-        assert!(!is_valid_date(2023, 2, 29)); // Feb 29 in non-leap year
-        assert!(!is_valid_date(1900, 2, 29)); // Feb 29 in non-leap year (divisible by 100 but not 400)
-        assert!(!is_valid_date(2024, 4, 31)); // April 31 doesn't exist
-        assert!(!is_valid_date(2024, 6, 31)); // June 31 doesn't exist
-        assert!(!is_valid_date(2024, 9, 31)); // September 31 doesn't exist
-        assert!(!is_valid_date(2024, 11, 31)); // November 31 doesn't exist
-        assert!(!is_valid_date(2024, 0, 15));  // Month 0 doesn't exist
-        assert!(!is_valid_date(2024, 13, 15)); // Month 13 doesn't exist
-        assert!(!is_valid_date(2024, 1, 0));   // Day 0 doesn't exist
-        assert!(!is_valid_date(2024, 1, 32));  // Day 32 doesn't exist
-    }
-
-    #[test]
-    fn extract_law_ids_ignores_invalid_dates() {
-        let text = "lov 31. februar 2027 nr. 99";
-        let ids = extract_law_ids(text);
-        assert!(ids.is_empty(), "Should reject invalid date like Feb 31");
-    }
-
-    #[test]
-    fn extract_enforcement_date_rejects_invalid_dates() {
-        let text = "Loven trer i kraft 31. februar 2027";
-        let result = extract_enforcement_date(text);
-        // Should fall through to no match since the invalid date is rejected
-        assert_eq!(result.value, ENFORCEMENT_PARSER_IKKE_FUNNET);
-    }
-
-    #[test]
-    fn map_norwegian_month_maps_known_and_unknown() {
-        assert_eq!(map_norwegian_month("januar"), Some("01"));
-        assert_eq!(map_norwegian_month("desember"), Some("12"));
-        assert_eq!(map_norwegian_month("not-a-month"), None);
-    }
-
     #[test]
     fn extract_between_comments_returns_inner_content() {
         let html = "before <!-- INNHOLD -->hello<!-- /INNHOLD --> after";
         assert_eq!(
-            extract_between_comments(html, "<!-- INNHOLD -->", "<!-- /INNHOLD -->").as_deref(),
+            extract_between_comments(html, "<!-- INNHOLD -->", "<!-- /INNHOLD -->"),
             Some("hello")
         );
     }
@@ -717,6 +1267,20 @@ mod tests {
         assert_eq!(text, "Hei verden");
     }
 
+    #[test]
+    fn strip_html_tags_handles_escapes_and_attributes_in_one_pass() {
+        let html = "\\r\\n <p class=\"lovtekst\">§ 1&nbsp;&nbsp;Formål</p>\\r\\nLoven&amp;";
+        assert_eq!(strip_html_tags(html), "§ 1 Formål Loven&amp;");
+    }
+
+    #[test]
+    fn innhold_section_runs_to_the_cut_of_a_truncated_body() {
+        let html = "<html><!-- INNHOLD --><p>Lov om endringar i";
+
+        assert_eq!(innhold_section(html, true), Some("<p>Lov om endringar i"));
+        assert_eq!(innhold_section(html, false), None);
+    }
+
     #[test]
     fn resolve_request_id_preserves_non_empty_value() {
         let result = resolve_request_id_with_generator(Some("req-abc"), || "generated".to_string());
@@ -732,84 +1296,13 @@ mod tests {
         assert_eq!(blank, "gen-2");
     }
 
-    #[test]
-    fn extract_enforcement_date_from_exact_date_fixture() {
-        let text = include_str!("fixtures/enforcement/exact_date.txt");
-        let result = extract_enforcement_date(text);
-
-        assert_eq!(result.value, "2027-01-01");
-        assert_eq!(result.source, "fixed_date");
-        assert_ne!(result.matched_snippet, ENFORCEMENT_SNIPPET_NONE);
-    }
-
-    #[test]
-    fn extract_enforcement_date_from_straks_fixture() {
-        let text = include_str!("fixtures/enforcement/straks.txt");
-        let result = extract_enforcement_date(text);
-
-        assert_eq!(result.value, ENFORCEMENT_STRAKS);
-        assert_eq!(result.source, "straks");
-    }
-
-    #[test]
-    fn extract_enforcement_date_from_kongen_fixture() {
-        let text = include_str!("fixtures/enforcement/kongen.txt");
-        let result = extract_enforcement_date(text);
-
-        assert_eq!(result.value, ENFORCEMENT_KONGEN_BESTEMMER);
-        assert_eq!(result.source, "kongen");
-    }
-
-    #[test]
-    fn extract_enforcement_date_from_multi_fixture() {
-        let text = include_str!("fixtures/enforcement/multi.txt");
-        let result = extract_enforcement_date(text);
-
-        assert_eq!(result.value, ENFORCEMENT_FLERE_DATOER);
-        assert_eq!(result.source, "multi");
-    }
-
-    #[test]
-    fn extract_enforcement_date_from_no_match_fixture() {
-        let text = include_str!("fixtures/enforcement/no_match.txt");
-        let result = extract_enforcement_date(text);
-
-        assert_eq!(result.value, ENFORCEMENT_PARSER_IKKE_FUNNET);
-        assert_eq!(result.source, "none");
-        assert_eq!(result.matched_snippet, ENFORCEMENT_SNIPPET_NONE);
-    }
-
-    #[test]
-    fn enforcement_priority_straks_over_kongen() {
-        let text = "Loven trer i kraft straks, fra den tid Kongen bestemmer.";
-        let result = extract_enforcement_date(text);
-
-        assert_eq!(result.value, ENFORCEMENT_STRAKS);
-    }
-
-    #[test]
-    fn enforcement_priority_kongen_over_fixed_date() {
-        let text = "Loven gjelder fra den tid Kongen bestemmer og trer i kraft 1. januar 2027.";
-        let result = extract_enforcement_date(text);
-
-        assert_eq!(result.value, ENFORCEMENT_KONGEN_BESTEMMER);
-    }
-
-    #[test]
-    fn enforcement_priority_fixed_date_over_multi_signal() {
-        let text = "Loven trer i kraft 1. januar 2027. § 2 trer i kraft til ulike tider.";
-        let result = extract_enforcement_date(text);
-
-        assert_eq!(result.value, "2027-01-01");
-    }
-
     #[test]
     fn snippet_around_match_is_bounded() {
         let long_prefix = "A".repeat(240);
         let text = format!("{} trer i kraft straks {}", long_prefix, "B".repeat(240));
 
-        let result = extract_enforcement_date(&text);
-        assert_eq!(result.value, ENFORCEMENT_STRAKS);
+        let result = extract_enforcement_date(&text, &LiteralHits::scan(&text));
+        assert_eq!(result.value, Enforcement::Immediate);
         assert!(result.matched_snippet.chars().count() <= 203);
     }
 
@@ -827,7 +1320,7 @@ mod tests {
         assert_eq!(attempts.get(), 3);
 
         let parser_result = parser_fail_result();
-        assert_eq!(parser_result.value, ENFORCEMENT_PARSER_FEIL);
+        assert_eq!(parser_result.value, Enforcement::ParserError);
     }
 
     #[test]
@@ -845,9 +1338,9 @@ mod tests {
         );
         let clean_text = clean_text_result.expect("fetch should succeed on first attempt");
 
-        let parsed = extract_enforcement_date(&clean_text);
+        let parsed = extract_enforcement_date(&clean_text, &LiteralHits::scan(&clean_text));
 
         assert_eq!(attempts.get(), 1);
-        assert_eq!(parsed.value, ENFORCEMENT_PARSER_IKKE_FUNNET);
+        assert_eq!(parsed.value, Enforcement::NotFound);
     }
 }
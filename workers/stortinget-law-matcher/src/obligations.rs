@@ -0,0 +1,262 @@
+use regex::Regex;
+use serde::Serialize;
+use std::sync::OnceLock;
+
+use crate::budget::ExtractionBudget;
+use crate::prefilter::{Literal, LiteralHits};
+use crate::references::{clause_end, clause_start};
+use crate::sections::{section_at, section_end};
+use crate::{snippet_around_match, truncate_chars};
+
+const MAX_ACTION_CHARS: usize = 300;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum Modal {
+    Shall,
+    May,
+    MustNot,
+}
+
+/// A modal verb phrase and the modal it expresses.
+struct ModalRule {
+    pattern: &'static str,
+    modal: Modal,
+}
+
+/// A regulated party, matched in any inflection and reported under one name.
+struct AddresseeRule {
+    pattern: &'static str,
+    addressee: &'static str,
+}
+
+/// Where phrases overlap the earlier rule wins, so "skal ikke" is a prohibition and not
+/// a duty.
+const MODAL_RULES: [ModalRule; 3] = [
+    ModalRule {
+        pattern: r"(?:skal|må|kan)\s+ikk(?:e|je)|har\s+ikk(?:e|je)\s+(?:lov|rett)\s+til\s+å",
+        modal: Modal::MustNot,
+    },
+    ModalRule {
+        pattern: r"skal|må|plikt(?:er|ar)\s+å|har\s+plikt\s+til\s+å|er\s+pliktig\s+til\s+å",
+        modal: Modal::Shall,
+    },
+    ModalRule {
+        pattern: r"kan|har\s+rett\s+til\s+å",
+        modal: Modal::May,
+    },
+];
+
+const ADDRESSEE_RULES: [AddresseeRule; 9] = [
+    AddresseeRule {
+        pattern: r"arbeidsgiver(?:en|e|ne)?|arbeidsgjevar(?:en|ar|ane)?",
+        addressee: "arbeidsgiver",
+    },
+    AddresseeRule {
+        pattern: r"arbeidstaker(?:en|e|ne)?|arbeidstakar(?:en|ar|ane)?",
+        addressee: "arbeidstaker",
+    },
+    AddresseeRule {
+        pattern: r"fylkeskommun(?:en|ene|ane|ar)",
+        addressee: "fylkeskommunen",
+    },
+    AddresseeRule {
+        pattern: r"kommun(?:en|ene|ane|ar)",
+        addressee: "kommunen",
+    },
+    AddresseeRule {
+        pattern: r"virksomhet(?:en|er|ene)?|verksemd(?:a|er|ene)?",
+        addressee: "virksomhet",
+    },
+    AddresseeRule {
+        pattern: r"tilbyder(?:en|e|ne)?|tilbydar(?:en|ar|ane)?",
+        addressee: "tilbyder",
+    },
+    AddresseeRule {
+        pattern: r"leverandør(?:en|er|ene|ar|ane)?",
+        addressee: "leverandør",
+    },
+    AddresseeRule {
+        pattern: r"utleier(?:en|e|ne)?|utleigar(?:en|ar|ane)?",
+        addressee: "utleier",
+    },
+    AddresseeRule {
+        pattern: r"(?:den\s+)?behandlingsansvarlige?",
+        addressee: "behandlingsansvarlig",
+    },
+];
+
+/// A duty, permission or prohibition placed on a regulated party: "Arbeidsgiveren skal
+/// varsle ..." gives addressee "arbeidsgiver", modal `shall` and action "varsle ...".
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub(crate) struct Obligation {
+    pub(crate) addressee: &'static str,
+    pub(crate) modal: Modal,
+    pub(crate) action: String,
+    pub(crate) section: Option<String>,
+    pub(crate) matched_snippet: String,
+}
+
+/// Finds modal clauses whose subject is a known addressee, either before the verb
+/// ("Kommunen skal ...") or right after it ("Etter søknad kan kommunen ...").
+pub(crate) fn extract_obligations(
+    text: &str,
+    literals: &LiteralHits,
+    budget: &mut ExtractionBudget,
+) -> Vec<Obligation> {
+    if !literals.contains(Literal::Section) {
+        return Vec::new();
+    }
+    static ADDRESSEE_RES: OnceLock<Vec<(Regex, &'static str)>> = OnceLock::new();
+    let addressee_res = ADDRESSEE_RES.get_or_init(|| {
+        ADDRESSEE_RULES
+            .iter()
+            .map(|rule| (rule_regex(rule.pattern), rule.addressee))
+            .collect()
+    });
+
+    let mut obligations = Vec::new();
+    for (modal, start, end) in modal_matches(text) {
+        let before = &text[clause_start(text, start)..start];
+        let after = &text[end..clause_end(text, end).min(section_end(text, end))];
+
+        let subject_before = addressee_res
+            .iter()
+            .filter_map(|(re, addressee)| {
+                re.find_iter(before).last().map(|m| (m.start(), *addressee))
+            })
+            .max_by_key(|(position, _)| *position)
+            .map(|(_, addressee)| (addressee, 0));
+        let subject_after = || {
+            let trimmed = after.trim_start();
+            addressee_res.iter().find_map(|(re, addressee)| {
+                re.find(trimmed)
+                    .filter(|m| m.start() == 0)
+                    .map(|m| (*addressee, after.len() - trimmed.len() + m.end()))
+            })
+        };
+        let Some((addressee, action_start)) = subject_before.or_else(subject_after) else {
+            continue;
+        };
+
+        let action = after[action_start..].trim();
+        let action = action
+            .strip_prefix("å ")
+            .unwrap_or(action)
+            .trim_end_matches([',', '.']);
+        if action.is_empty() {
+            continue;
+        }
+        if !budget.allows_match(obligations.len()) {
+            break;
+        }
+
+        obligations.push(Obligation {
+            addressee,
+            modal,
+            action: truncate_chars(action, MAX_ACTION_CHARS),
+            section: section_at(text, start),
+            matched_snippet: snippet_around_match(text, start, end),
+        });
+    }
+
+    obligations
+}
+
+/// Modal phrases in text order, each claimed by the first rule that matches it.
+fn modal_matches(text: &str) -> Vec<(Modal, usize, usize)> {
+    static MODAL_RES: OnceLock<Vec<(Regex, Modal)>> = OnceLock::new();
+    let modal_res = MODAL_RES.get_or_init(|| {
+        MODAL_RULES
+            .iter()
+            .map(|rule| (rule_regex(rule.pattern), rule.modal))
+            .collect()
+    });
+
+    let mut matches: Vec<(Modal, usize, usize)> = Vec::new();
+    for (re, modal) in modal_res {
+        for m in re.find_iter(text) {
+            if matches
+                .iter()
+                .all(|(_, start, end)| m.end() <= *start || m.start() >= *end)
+            {
+                matches.push((*modal, m.start(), m.end()));
+            }
+        }
+    }
+    matches.sort_by_key(|(_, start, _)| *start);
+    matches
+}
+
+/// Rule patterns match whole words, case-insensitively.
+fn rule_regex(pattern: &str) -> Regex {
+    Regex::new(&format!(r"(?i)\b(?:{})\b", pattern)).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracts_obligations_with_addressee_modal_and_section() {
+        let obligations = extract_obligations(
+            include_str!("fixtures/obligations/obligations.txt"),
+            &LiteralHits::scan(include_str!("fixtures/obligations/obligations.txt")),
+            &mut ExtractionBudget::default(),
+        );
+
+        assert_eq!(obligations.len(), 5);
+
+        assert_eq!(obligations[0].addressee, "arbeidsgiver");
+        assert_eq!(obligations[0].modal, Modal::Shall);
+        assert_eq!(
+            obligations[0].action,
+            "varsle Arbeidstilsynet om alvorlige ulykker"
+        );
+        assert_eq!(obligations[0].section.as_deref(), Some("§ 4"));
+
+        assert_eq!(obligations[1].addressee, "virksomhet");
+        assert_eq!(obligations[1].action, "føre logg over tilgang");
+
+        assert_eq!(obligations[2].addressee, "kommunen");
+        assert_eq!(obligations[2].section.as_deref(), Some("§ 5"));
+
+        assert_eq!(obligations[3].addressee, "kommunen");
+        assert_eq!(obligations[3].modal, Modal::May);
+        assert_eq!(obligations[3].action, "gi tilskudd til private tiltak");
+
+        assert_eq!(obligations[4].addressee, "tilbyder");
+        assert_eq!(obligations[4].modal, Modal::MustNot);
+        assert_eq!(obligations[4].action, "kreve gebyr for oppsigelse");
+        assert_eq!(obligations[4].section.as_deref(), Some("§ 6"));
+    }
+
+    #[test]
+    fn accepts_nynorsk_obligations() {
+        let obligations = extract_obligations(
+            "§ 2 Plikter Verksemda pliktar å rapportere kvart år. Arbeidsgjevaren må ikkje \
+             krevje gebyr.",
+            &LiteralHits::scan(
+                "§ 2 Plikter Verksemda pliktar å rapportere kvart år. Arbeidsgjevaren må ikkje \
+             krevje gebyr.",
+            ),
+            &mut ExtractionBudget::default(),
+        );
+
+        assert_eq!(obligations.len(), 2);
+        assert_eq!(obligations[0].addressee, "virksomhet");
+        assert_eq!(obligations[0].action, "rapportere kvart år");
+        assert_eq!(obligations[1].addressee, "arbeidsgiver");
+        assert_eq!(obligations[1].modal, Modal::MustNot);
+    }
+
+    #[test]
+    fn skips_clauses_without_known_addressee() {
+        assert!(extract_obligations(
+            "Loven skal evalueres innen fem år.",
+            &LiteralHits::scan("Loven skal evalueres innen fem år."),
+            &mut ExtractionBudget::default()
+        )
+        .is_empty());
+    }
+}
@@ -0,0 +1,288 @@
+use serde::Serialize;
+
+use crate::budget::ExtractionBudget;
+use crate::dates::{amount_value, period_unit, PeriodUnit, PERIOD_UNIT_PATTERN};
+use crate::prefilter::{Literal, LiteralHits};
+use crate::references::{clause_end, clause_start};
+use crate::sections::{section_at, section_end, section_start};
+use crate::snippet_around_match;
+
+/// "straffes med", nynorsk "straffast med" / "vert straffa med".
+const CRIMINAL_PATTERN: &str = r"\b(?:straffes|straffast|(?:blir|vert)\s+straffa)\s+med\b";
+const INFRINGEMENT_FEE_PATTERN: &str =
+    r"\b(?:overtredelsesgebyr|overtredingsgebyr|lovbrotsgebyr)(?:et|er|ene|a|ar|ane)?\b";
+const COERCIVE_FINE_PATTERN: &str = r"\btvangsmulkt(?:en|er|ene|a|ar|ane)?\b";
+
+const FINE_PATTERN: &str = r"(?i)\b(?:bot|bøter|bøtar)\b";
+
+/// Amounts as multiples of the grunnbeløp ("15 ganger grunnbeløpet", "1,5 G") or in
+/// kroner ("kr 100 000", "50 000 kroner", "2 millioner kroner").
+const AMOUNT_PATTERN: &str = r"(?i)(\d+(?:,\d+)?|[a-zæøå]+)\s+(?:ganger\s+(?:folketrygdens\s+)?grunnbeløpet|(?-i:G)\b)|(?:\bkr\.?|\bNOK)\s*(\d{1,3}(?:[ .]\d{3})*(?:,\d+)?)(\s+million(?:er|ar)?)?|\b(\d{1,3}(?:[ .]\d{3})*(?:,\d+)?)(\s+million(?:er|ar)?)?\s+kroner\b";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum SanctionKind {
+    /// "straffes med bot eller fengsel"
+    Criminal,
+    /// Overtredelsesgebyr.
+    InfringementFee,
+    /// Tvangsmulkt.
+    CoerciveFine,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum AmountUnit {
+    Kroner,
+    /// Multiples of folketrygdens grunnbeløp (G).
+    BaseAmount,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub(crate) struct PenaltyAmount {
+    pub(crate) value: f64,
+    pub(crate) unit: AmountUnit,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub(crate) struct ImprisonmentTerm {
+    pub(crate) amount: u32,
+    pub(crate) unit: PeriodUnit,
+}
+
+/// The sanctions of one kind in a §. Criminal provisions report whether a fine is
+/// available and the longest prison term; amounts are every sum the § names for the
+/// sanction, in order.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub(crate) struct Penalty {
+    pub(crate) kind: SanctionKind,
+    pub(crate) section: Option<String>,
+    pub(crate) fine: bool,
+    pub(crate) max_imprisonment: Option<ImprisonmentTerm>,
+    pub(crate) amounts: Vec<PenaltyAmount>,
+    pub(crate) matched_snippet: String,
+}
+
+pub(crate) fn extract_penalties(
+    text: &str,
+    literals: &LiteralHits,
+    budget: &mut ExtractionBudget,
+) -> Vec<Penalty> {
+    if !literals.contains(Literal::Section) {
+        return Vec::new();
+    }
+    let mut mentions: Vec<(SanctionKind, usize, usize)> = Vec::new();
+    for (kind, re) in [
+        (
+            SanctionKind::Criminal,
+            cached_regex!(format!("(?i){}", CRIMINAL_PATTERN)),
+        ),
+        (
+            SanctionKind::InfringementFee,
+            cached_regex!(format!("(?i){}", INFRINGEMENT_FEE_PATTERN)),
+        ),
+        (
+            SanctionKind::CoerciveFine,
+            cached_regex!(format!("(?i){}", COERCIVE_FINE_PATTERN)),
+        ),
+    ] {
+        mentions.extend(re.find_iter(text).map(|m| (kind, m.start(), m.end())));
+    }
+    mentions.sort_by_key(|(_, start, _)| *start);
+
+    let fine_re = cached_regex!(FINE_PATTERN);
+    let prison_re = cached_regex!(format!(
+        r"(?i)\bfengsel\s+(?:i\s+)?(?:inntil|opptil|høyst|høgst)\s+(\d{{1,2}}|[a-zæøå]+)\s+({})\b",
+        PERIOD_UNIT_PATTERN
+    ));
+    let amount_re = cached_regex!(AMOUNT_PATTERN);
+
+    let mut penalties: Vec<Penalty> = Vec::new();
+    for (kind, start, end) in mentions {
+        // Measured from the end so a "§ 13 Overtredelsesgebyr" heading counts as § 13.
+        let section = section_at(text, end);
+        let clause_from = clause_start(text, start).max(section_start(text, end));
+        let clause_to = clause_end(text, end).min(section_end(text, end));
+        let clause = &text[clause_from..clause_to];
+
+        let fine = kind == SanctionKind::Criminal && fine_re.is_match(clause);
+        let imprisonment = if kind == SanctionKind::Criminal {
+            prison_re.captures_iter(clause).find_map(|cap| {
+                Some(ImprisonmentTerm {
+                    amount: amount_value(&cap[1])?,
+                    unit: period_unit(&cap[2]),
+                })
+            })
+        } else {
+            None
+        };
+        let amounts: Vec<PenaltyAmount> = amount_re
+            .captures_iter(clause)
+            .filter_map(|cap| amount(&cap))
+            .collect();
+
+        let penalty = match penalties
+            .iter_mut()
+            .find(|penalty| penalty.kind == kind && penalty.section == section)
+        {
+            Some(penalty) => penalty,
+            None => {
+                if !budget.allows_match(penalties.len()) {
+                    break;
+                }
+                penalties.push(Penalty {
+                    kind,
+                    section,
+                    fine: false,
+                    max_imprisonment: None,
+                    amounts: Vec::new(),
+                    matched_snippet: snippet_around_match(text, start, end),
+                });
+                penalties.last_mut().unwrap()
+            }
+        };
+
+        penalty.fine |= fine;
+        if imprisonment.map(term_in_days) > penalty.max_imprisonment.map(term_in_days) {
+            penalty.max_imprisonment = imprisonment;
+        }
+        for amount in amounts {
+            if !penalty.amounts.contains(&amount) {
+                penalty.amounts.push(amount);
+            }
+        }
+    }
+
+    penalties
+}
+
+fn amount(cap: &regex::Captures<'_>) -> Option<PenaltyAmount> {
+    if let Some(multiple) = cap.get(1) {
+        let value = parse_number(multiple.as_str())
+            .or_else(|| amount_value(multiple.as_str()).map(f64::from))?;
+        return Some(PenaltyAmount {
+            value,
+            unit: AmountUnit::BaseAmount,
+        });
+    }
+
+    let (number, millions) = match cap.get(2) {
+        Some(number) => (number, cap.get(3)),
+        None => (cap.get(4)?, cap.get(5)),
+    };
+    let value = parse_number(number.as_str())?;
+    Some(PenaltyAmount {
+        value: if millions.is_some() {
+            value * 1_000_000.0
+        } else {
+            value
+        },
+        unit: AmountUnit::Kroner,
+    })
+}
+
+/// "50 000", "50.000" and "1,5" as Norwegian-formatted numbers.
+fn parse_number(value: &str) -> Option<f64> {
+    value
+        .replace([' ', '.'], "")
+        .replace(',', ".")
+        .parse::<f64>()
+        .ok()
+}
+
+fn term_in_days(term: ImprisonmentTerm) -> u32 {
+    match term.unit {
+        PeriodUnit::Days => term.amount,
+        PeriodUnit::Weeks => term.amount * 7,
+        PeriodUnit::Months => term.amount * 30,
+        PeriodUnit::Years => term.amount * 365,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracts_sanctions_per_section() {
+        let penalties = extract_penalties(
+            include_str!("fixtures/penalties/penalties.txt"),
+            &LiteralHits::scan(include_str!("fixtures/penalties/penalties.txt")),
+            &mut ExtractionBudget::default(),
+        );
+
+        assert_eq!(penalties.len(), 3);
+
+        assert_eq!(penalties[0].kind, SanctionKind::Criminal);
+        assert_eq!(penalties[0].section.as_deref(), Some("§ 12"));
+        assert!(penalties[0].fine);
+        assert_eq!(
+            penalties[0].max_imprisonment,
+            Some(ImprisonmentTerm {
+                amount: 3,
+                unit: PeriodUnit::Years
+            })
+        );
+
+        assert_eq!(penalties[1].kind, SanctionKind::InfringementFee);
+        assert_eq!(penalties[1].section.as_deref(), Some("§ 13"));
+        assert_eq!(
+            penalties[1].amounts,
+            vec![PenaltyAmount {
+                value: 15.0,
+                unit: AmountUnit::BaseAmount
+            }]
+        );
+
+        assert_eq!(penalties[2].kind, SanctionKind::CoerciveFine);
+        assert_eq!(penalties[2].section.as_deref(), Some("§ 14"));
+        assert_eq!(
+            penalties[2].amounts,
+            vec![PenaltyAmount {
+                value: 50_000.0,
+                unit: AmountUnit::Kroner
+            }]
+        );
+    }
+
+    #[test]
+    fn reads_nynorsk_and_kroner_amounts() {
+        let penalties = extract_penalties(
+            "§ 8 Straff Brot på § 3 vert straffa med bøter eller fengsel i inntil seks \
+             månader. Lovbrotsgebyret kan vere inntil kr 2 millionar.",
+            &LiteralHits::scan(
+                "§ 8 Straff Brot på § 3 vert straffa med bøter eller fengsel i inntil seks \
+             månader. Lovbrotsgebyret kan vere inntil kr 2 millionar.",
+            ),
+            &mut ExtractionBudget::default(),
+        );
+
+        assert_eq!(penalties.len(), 2);
+        assert_eq!(
+            penalties[0].max_imprisonment,
+            Some(ImprisonmentTerm {
+                amount: 6,
+                unit: PeriodUnit::Months
+            })
+        );
+        assert!(penalties[0].amounts.is_empty());
+        assert_eq!(penalties[1].kind, SanctionKind::InfringementFee);
+        assert_eq!(
+            penalties[1].amounts,
+            vec![PenaltyAmount {
+                value: 2_000_000.0,
+                unit: AmountUnit::Kroner
+            }]
+        );
+    }
+
+    #[test]
+    fn ignores_references_to_other_penal_provisions() {
+        assert!(extract_penalties(
+            "Overtredelse straffes etter straffeloven § 171.",
+            &LiteralHits::scan("Overtredelse straffes etter straffeloven § 171."),
+            &mut ExtractionBudget::default()
+        )
+        .is_empty());
+    }
+}
@@ -0,0 +1,134 @@
+use aho_corasick::{AhoCorasick, AhoCorasickBuilder, MatchKind};
+use std::ops::Range;
+use std::sync::OnceLock;
+
+/// Words the expensive pattern families cannot match without. The fetch handler scans the
+/// text once and hands the hits to every extractor, so each can skip a text, or try its
+/// pattern only where the literal occurs, before any regex runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Literal {
+    /// "lov" in law citations ("lov 17. juni 2005 nr. 62").
+    Lov,
+    /// "forskrift" in regulation grants.
+    Forskrift,
+    /// "trer i kraft", nynorsk "tek til å gjelde".
+    InForce,
+    /// "Kongen bestemmer", "Kongen fastset".
+    Kongen,
+    /// "§", which every numbered provision starts with.
+    Section,
+}
+
+impl Literal {
+    fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+/// Matched ASCII case-insensitively, which covers "Lov", "LOV" and "Kongen". The in-force
+/// phrases are split on whitespace by the regexes, so only their fixed word is listed.
+const LITERALS: [(&str, Literal); 6] = [
+    ("lov", Literal::Lov),
+    ("forskrift", Literal::Forskrift),
+    ("kraft", Literal::InForce),
+    ("gjelde", Literal::InForce),
+    ("kongen", Literal::Kongen),
+    ("§", Literal::Section),
+];
+
+/// Where each literal occurs in a text, in text order, and which ones occur at all.
+#[derive(Debug)]
+pub(crate) struct LiteralHits {
+    hits: Vec<(Literal, usize)>,
+    present: u8,
+}
+
+impl LiteralHits {
+    pub(crate) fn scan(text: &str) -> Self {
+        static AUTOMATON: OnceLock<AhoCorasick> = OnceLock::new();
+        let automaton = AUTOMATON.get_or_init(|| {
+            AhoCorasickBuilder::new()
+                .ascii_case_insensitive(true)
+                .match_kind(MatchKind::LeftmostFirst)
+                .build(LITERALS.iter().map(|(literal, _)| literal))
+                .unwrap()
+        });
+
+        let hits = automaton
+            .find_iter(text)
+            .map(|m| (LITERALS[m.pattern().as_usize()].1, m.start()))
+            .collect();
+        Self::from_hits(hits)
+    }
+
+    fn from_hits(hits: Vec<(Literal, usize)>) -> Self {
+        let present = hits
+            .iter()
+            .fold(0, |present, (literal, _)| present | literal.bit());
+        Self { hits, present }
+    }
+
+    /// The hits inside `range` of the scanned text, with offsets relative to its start, for
+    /// extractors that run on one segment of it.
+    pub(crate) fn within(&self, range: Range<usize>) -> Self {
+        let hits = self
+            .hits
+            .iter()
+            .filter(|(_, start)| range.contains(start))
+            .map(|(literal, start)| (*literal, start - range.start))
+            .collect();
+        Self::from_hits(hits)
+    }
+
+    pub(crate) fn contains(&self, literal: Literal) -> bool {
+        self.present & literal.bit() != 0
+    }
+
+    /// Byte offsets where `literal` starts, in text order.
+    pub(crate) fn positions(&self, literal: Literal) -> impl Iterator<Item = usize> + '_ {
+        self.hits
+            .iter()
+            .filter(move |(hit, _)| *hit == literal)
+            .map(|(_, start)| *start)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_literals_case_insensitively() {
+        let hits = LiteralHits::scan("LOV om endring. Kongen kan gi forskrift.");
+
+        assert!(hits.contains(Literal::Lov));
+        assert!(hits.contains(Literal::Kongen));
+        assert!(hits.contains(Literal::Forskrift));
+        assert!(!hits.contains(Literal::InForce));
+        assert!(!hits.contains(Literal::Section));
+        assert!(LiteralHits::scan("§ 3 skal lyde:").contains(Literal::Section));
+        assert!(LiteralHits::scan("Lova tek til å gjelde straks.").contains(Literal::InForce));
+    }
+
+    #[test]
+    fn lists_positions_in_text_order() {
+        let text = "Lov om endring i lov 17. juni 2005 nr. 62 og forlov";
+        let hits = LiteralHits::scan(text);
+
+        assert_eq!(
+            hits.positions(Literal::Lov).collect::<Vec<_>>(),
+            vec![0, 17, 48]
+        );
+        assert_eq!(hits.positions(Literal::Kongen).count(), 0);
+    }
+
+    #[test]
+    fn rebases_hits_within_a_segment() {
+        let text = "A. Lov om skatt § 1 B. Lov om avgift trer i kraft";
+        let segment = LiteralHits::scan(text).within(21..text.len());
+
+        assert_eq!(segment.positions(Literal::Lov).collect::<Vec<_>>(), vec![3]);
+        assert!(segment.contains(Literal::InForce));
+        assert!(!segment.contains(Literal::Section));
+    }
+}
@@ -0,0 +1,659 @@
+use serde::Serialize;
+use std::collections::HashMap;
+
+use crate::budget::ExtractionBudget;
+use crate::dates::{date_pattern, parse_date};
+use crate::prefilter::{Literal, LiteralHits};
+use crate::sections::heading_titles;
+use crate::{clamp_to_char_boundary_end, clamp_to_char_boundary_start, snippet_around_match};
+
+const CLAUSE_CONTEXT_BYTES: usize = 240;
+/// Bytes of a heading title compared against `HEADING_ROLES`.
+const HEADING_TITLE_BYTES: usize = 24;
+
+const RULE_LAW_DATE_NUMBER: &str = "law_date_number";
+const BASE_CONFIDENCE: f64 = 0.9;
+
+/// Commas do not end a title: "om arbeidsmiljø, arbeidstid og stillingsvern mv." is one.
+const TITLE_STOP_CHARS: [char; 6] = ['.', ';', ':', '(', ')', '§'];
+/// Verbs that end the clause after a title, and the next citation in a list of laws.
+const TITLE_STOP_WORDS: [&str; 15] = [
+    " gjøres ",
+    " gjer ",
+    " endres",
+    " endrast",
+    " oppheves",
+    " vert ",
+    " blir ",
+    " trer ",
+    " tek ",
+    " skal ",
+    " jf",
+    ", lov ",
+    " og lov ",
+    ", forskrift ",
+    " og forskrift ",
+];
+
+/// Part and § heading titles that fix the role of the laws cited under them, bokmål and
+/// nynorsk.
+const HEADING_ROLES: [(&str, ReferenceRole); 10] = [
+    ("endringer i", ReferenceRole::Amended),
+    ("endringar i", ReferenceRole::Amended),
+    ("endring i", ReferenceRole::Amended),
+    ("opphevelse", ReferenceRole::Repealed),
+    ("oppheving", ReferenceRole::Repealed),
+    ("hjemmel", ReferenceRole::Cited),
+    ("heimel", ReferenceRole::Cited),
+    ("ikrafttredelse", ReferenceRole::Enacting),
+    ("ikraftsetjing", ReferenceRole::Enacting),
+    ("ikraftsetting", ReferenceRole::Enacting),
+];
+
+/// How a law is referenced by the decision. Variants are ordered by precedence, so a
+/// law that is both cited and amended somewhere in the text is reported as amended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum ReferenceRole {
+    Cited,
+    Enacting,
+    Amended,
+    Repealed,
+}
+
+/// A law cited in the decision text. Evidence fields (`raw`, `snippet`, offsets) describe
+/// the first occurrence; offsets are character positions in the cleaned text.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub(crate) struct LawReference {
+    pub(crate) id: String,
+    pub(crate) role: ReferenceRole,
+    pub(crate) raw: String,
+    pub(crate) snippet: String,
+    pub(crate) start: usize,
+    pub(crate) end: usize,
+    pub(crate) occurrences: usize,
+    pub(crate) rule: &'static str,
+    pub(crate) confidence: f64,
+    /// The "om ..." title following the citation, used to cross-check the ID.
+    pub(crate) title: Option<String>,
+    /// The parenthesised short title after the citation, "(arbeidsmiljøloven)".
+    pub(crate) short_title: Option<String>,
+    pub(crate) suggested_id: Option<String>,
+    pub(crate) needs_review: bool,
+}
+
+/// Extracts "lov D. måned ÅÅÅÅ nr. N" citations as Lovdata-style IDs, one entry per law in
+/// order of first appearance, until the budget's match limit.
+pub(crate) fn extract_law_references(
+    text: &str,
+    literals: &LiteralHits,
+    budget: &mut ExtractionBudget,
+) -> Vec<LawReference> {
+    // Anchored: it is only tried where the pre-filter found "lov".
+    let re = cached_regex!(format!(
+        r"(?i)^lov\s+({})\s+nr(\.)?\s+(\d+)",
+        date_pattern()
+    ));
+    let mut references: Vec<LawReference> = Vec::new();
+    let mut index_by_id: HashMap<String, usize> = HashMap::new();
+    let mut char_cursor = (0usize, 0usize);
+
+    let mut previous_end = 0;
+    let headings = heading_titles(text);
+
+    for offset in literals.positions(Literal::Lov) {
+        if offset < previous_end {
+            continue;
+        }
+        let Some(cap) = re.captures(&text[offset..]) else {
+            continue;
+        };
+        let full_match = cap.get(0).unwrap();
+        let (match_start, match_end) = (offset, offset + full_match.end());
+        let previous_citation_end = previous_end;
+        previous_end = match_end;
+        // Rejects unknown months and invalid dates such as "31. februar 2027"
+        let Some(date) = parse_date(&cap[1]) else {
+            continue;
+        };
+        let law_id = format!("LOV-{}-{}", date, &cap[3]);
+        let role = classify_reference_role(
+            text,
+            &headings,
+            previous_citation_end,
+            match_start,
+            match_end,
+        );
+
+        if let Some(&idx) = index_by_id.get(&law_id) {
+            let existing = &mut references[idx];
+            existing.occurrences += 1;
+            existing.role = existing.role.max(role);
+            continue;
+        }
+
+        if !budget.allows_match(references.len()) {
+            break;
+        }
+        let start = char_offset(text, &mut char_cursor, match_start);
+        let end = start + full_match.as_str().chars().count();
+        index_by_id.insert(law_id.clone(), references.len());
+        references.push(LawReference {
+            id: law_id,
+            role,
+            raw: full_match.as_str().to_string(),
+            snippet: snippet_around_match(text, match_start, match_end),
+            start,
+            end,
+            occurrences: 1,
+            rule: RULE_LAW_DATE_NUMBER,
+            confidence: citation_confidence(text, match_end, cap.get(2).is_some()),
+            title: cited_title(text, match_end),
+            short_title: cited_short_title(text, match_end),
+            suggested_id: None,
+            needs_review: false,
+        });
+    }
+
+    references
+}
+
+pub(crate) fn count_role(references: &[LawReference], role: ReferenceRole) -> usize {
+    references.iter().filter(|r| r.role == role).count()
+}
+
+/// Matches are found left to right, so the byte-to-char conversion resumes from the
+/// previous position instead of rescanning the text for every reference.
+fn char_offset(text: &str, cursor: &mut (usize, usize), byte_idx: usize) -> usize {
+    let (last_byte, last_char) = *cursor;
+    let chars = last_char + text[last_byte..byte_idx].chars().count();
+    *cursor = (byte_idx, chars);
+    chars
+}
+
+/// Reads the "om ..." title after a citation up to the sentence end, a "§", a parenthesis or
+/// the verb that ends the clause, e.g. "om helseregistre" in "lov ... nr. 60 om
+/// helseregistre gjøres ...".
+pub(crate) fn cited_title(text: &str, end: usize) -> Option<String> {
+    split_cited_title(&text[end..])
+        .0
+        .map(|title| format!("om {}", title))
+}
+
+/// Reads the parenthesised short title after a citation and its "om ..." title, e.g.
+/// "arbeidsmiljøloven" in "lov ... nr. 62 om arbeidsmiljø ... mv. (arbeidsmiljøloven)".
+pub(crate) fn cited_short_title(text: &str, end: usize) -> Option<String> {
+    let inner = split_cited_title(&text[end..])
+        .1
+        .trim_start()
+        .strip_prefix('(')?;
+    let short_title = inner[..inner.find(')')?].trim();
+
+    if short_title.is_empty() || short_title.contains(char::is_whitespace) {
+        None
+    } else {
+        Some(short_title.to_string())
+    }
+}
+
+/// Splits the text after a citation into its title, without the leading "om", and the text
+/// following the title.
+fn split_cited_title(after: &str) -> (Option<&str>, &str) {
+    let rest = after.trim_start();
+    let Some(body) = rest
+        .strip_prefix("om ")
+        .or_else(|| rest.strip_prefix("Om "))
+    else {
+        return (None, rest);
+    };
+
+    let mut stop = body.find(TITLE_STOP_CHARS).unwrap_or(body.len());
+    // "mv." closes the title rather than the sentence.
+    if body[stop..].starts_with('.') && body[..stop].ends_with(" mv") {
+        stop += 1;
+    }
+    let stop = TITLE_STOP_WORDS
+        .iter()
+        .filter_map(|word| body[..stop].find(word))
+        .min()
+        .unwrap_or(stop);
+
+    let title = body[..stop].trim();
+    ((!title.is_empty()).then_some(title), &body[stop..])
+}
+
+/// A citation followed by its "om ..." title is unambiguous; "nr" without the period is a
+/// common OCR/typing slip and slightly less trustworthy.
+fn citation_confidence(text: &str, end: usize, has_nr_period: bool) -> f64 {
+    let mut confidence = BASE_CONFIDENCE;
+    if text[end..]
+        .trim_start()
+        .get(..3)
+        .is_some_and(|prefix| prefix.eq_ignore_ascii_case("om "))
+    {
+        confidence += 0.05;
+    }
+    if !has_nr_period {
+        confidence -= 0.1;
+    }
+    confidence
+}
+
+/// Derives the role of a reference from the part or § heading it opens, or otherwise from
+/// the clause around it. `previous_end` is where the citation before it ends.
+fn classify_reference_role(
+    text: &str,
+    headings: &[usize],
+    previous_end: usize,
+    start: usize,
+    end: usize,
+) -> ReferenceRole {
+    heading_role(text, headings, previous_end, start)
+        .unwrap_or_else(|| clause_role(text, start, end))
+}
+
+/// The role set by the nearest heading before `start`, e.g. `Amended` under "II Endringer i
+/// andre lover". It only covers the law named in the heading's opening clause ("I lov ...
+/// gjøres følgende endring"); laws cited further down, such as in the amended provisions
+/// themselves, are left to their own clause.
+fn heading_role(
+    text: &str,
+    headings: &[usize],
+    previous_end: usize,
+    start: usize,
+) -> Option<ReferenceRole> {
+    let idx = headings.partition_point(|&title| title <= start);
+    let title_start = headings[idx.checked_sub(1)?];
+    if previous_end > title_start || (title_start..start).any(|i| is_clause_boundary(text, i)) {
+        return None;
+    }
+    let title_end = clamp_to_char_boundary_end(text, title_start + HEADING_TITLE_BYTES);
+    let title = text[title_start..title_end].to_lowercase();
+
+    HEADING_ROLES
+        .iter()
+        .find(|(prefix, _)| title.starts_with(prefix))
+        .map(|(_, role)| *role)
+}
+
+/// The role implied by the clause around a reference: the text between the nearest clause
+/// boundaries (`:`, `;` or a sentence end) on either side of the match.
+fn clause_role(text: &str, start: usize, end: usize) -> ReferenceRole {
+    let before = text[clause_start(text, start)..start].to_lowercase();
+    let after = text[end..clause_end(text, end)].to_lowercase();
+    let mentions = |needles: &[&str]| {
+        needles
+            .iter()
+            .any(|needle| before.contains(needle) || after.contains(needle))
+    };
+
+    // Bokmål and nynorsk forms: "oppheves"/"vert oppheva", "endres"/"endrast".
+    if mentions(&["oppheves", "oppheva", "oppheving av", "opphevelse av"]) {
+        ReferenceRole::Repealed
+    } else if before.contains("endring")
+        || mentions(&["følgende endring", "følgjande endring", "endres", "endrast"])
+    {
+        ReferenceRole::Amended
+    } else if before.contains("ikrafttredelse") || before.contains("ikraftsetjing") {
+        ReferenceRole::Enacting
+    } else {
+        ReferenceRole::Cited
+    }
+}
+
+fn is_clause_boundary(text: &str, idx: usize) -> bool {
+    let bytes = text.as_bytes();
+    match bytes[idx] {
+        b':' | b';' => true,
+        // "16. juni" and "nr. 60" continue the clause, ". Loven" starts a new one.
+        b'.' => text[idx + 1..]
+            .strip_prefix(' ')
+            .and_then(|rest| rest.chars().next())
+            .is_some_and(char::is_uppercase),
+        _ => false,
+    }
+}
+
+pub(crate) fn clause_start(text: &str, start: usize) -> usize {
+    let floor = clamp_to_char_boundary_start(text, start.saturating_sub(CLAUSE_CONTEXT_BYTES));
+    (floor..start)
+        .rev()
+        .find(|&idx| is_clause_boundary(text, idx))
+        .map(|idx| idx + 1)
+        .unwrap_or(floor)
+}
+
+pub(crate) fn clause_end(text: &str, end: usize) -> usize {
+    let ceiling = clamp_to_char_boundary_end(text, end + CLAUSE_CONTEXT_BYTES);
+    (end..ceiling)
+        .find(|&idx| is_clause_boundary(text, idx))
+        .unwrap_or(ceiling)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::budget::Truncation;
+
+    fn extract_law_ids(text: &str) -> Vec<String> {
+        let mut ids: Vec<String> = extract_law_references(
+            text,
+            &LiteralHits::scan(text),
+            &mut ExtractionBudget::default(),
+        )
+        .into_iter()
+        .map(|r| r.id)
+        .collect();
+        ids.sort();
+        ids
+    }
+
+    fn role_of(references: &[LawReference], id: &str) -> Option<ReferenceRole> {
+        references.iter().find(|r| r.id == id).map(|r| r.role)
+    }
+
+    #[test]
+    fn stops_at_the_match_limit_but_counts_repeats() {
+        let text = "lov 16. juni 2017 nr. 60, lov 16. juni 2017 nr. 60 og lov 3. mars 1995 nr. 10";
+        let mut budget = ExtractionBudget::new(text.len(), 1);
+        let references = extract_law_references(text, &LiteralHits::scan(text), &mut budget);
+
+        assert_eq!(references.len(), 1);
+        assert_eq!(references[0].occurrences, 2);
+        assert_eq!(budget.truncation(), &[Truncation::Matches]);
+
+        let mut roomy = ExtractionBudget::new(text.len(), 2);
+        assert_eq!(
+            extract_law_references(text, &LiteralHits::scan(text), &mut roomy).len(),
+            2
+        );
+        assert!(!roomy.truncated());
+    }
+
+    #[test]
+    fn extract_law_ids_finds_and_deduplicates_ids() {
+        let text = "lov 16. juni 2017 nr. 60 og LOV 16. juni 2017 nr 60";
+        let ids = extract_law_ids(text);
+
+        assert_eq!(ids, vec!["LOV-2017-06-16-60".to_string()]);
+    }
+
+    #[test]
+    fn extract_law_ids_ignores_unknown_month() {
+        let text = "lov 12. foo 2024 nr. 1";
+        let ids = extract_law_ids(text);
+        assert!(ids.is_empty());
+    }
+
+    #[test]
+    fn extract_law_ids_ignores_invalid_dates() {
+        let text = "lov 31. februar 2027 nr. 99";
+        let ids = extract_law_ids(text);
+        assert!(ids.is_empty(), "Should reject invalid date like Feb 31");
+    }
+
+    #[test]
+    fn extract_law_ids_accepts_abbreviated_and_numeric_dates() {
+        let text = "lov 16. jun. 2017 nr. 60 og lov 03.03.1995 nr. 10";
+        let ids = extract_law_ids(text);
+        assert_eq!(ids, vec!["LOV-1995-03-03-10", "LOV-2017-06-16-60"]);
+    }
+
+    #[test]
+    fn reference_roles_follow_document_structure() {
+        let text = include_str!("fixtures/references/roles.txt");
+        let references = extract_law_references(
+            text,
+            &LiteralHits::scan(text),
+            &mut ExtractionBudget::default(),
+        );
+
+        assert_eq!(
+            role_of(&references, "LOV-2017-06-16-60"),
+            Some(ReferenceRole::Amended)
+        );
+        assert_eq!(
+            role_of(&references, "LOV-1995-03-03-10"),
+            Some(ReferenceRole::Repealed)
+        );
+        assert_eq!(
+            role_of(&references, "LOV-2005-06-17-62"),
+            Some(ReferenceRole::Cited)
+        );
+        assert_eq!(references.len(), 3);
+    }
+
+    #[test]
+    fn law_whose_amendments_enter_into_force_is_amended() {
+        let references = extract_law_references(
+            "Endringene i lov 20. desember 2024 nr. 90 om digital postkasse trer i kraft \
+             1. januar 2027.",
+            &LiteralHits::scan(
+                "Endringene i lov 20. desember 2024 nr. 90 om digital postkasse trer i kraft \
+             1. januar 2027.",
+            ),
+            &mut ExtractionBudget::default(),
+        );
+
+        assert_eq!(references[0].role, ReferenceRole::Amended);
+    }
+
+    #[test]
+    fn reference_roles_follow_part_and_section_headings() {
+        let text = include_str!("fixtures/references/headings.txt");
+        let references = extract_law_references(
+            text,
+            &LiteralHits::scan(text),
+            &mut ExtractionBudget::default(),
+        );
+
+        assert_eq!(
+            role_of(&references, "LOV-2015-06-04-39"),
+            Some(ReferenceRole::Amended)
+        );
+        // Neither clause says "oppheves" or "endres" about the law; the heading does.
+        assert_eq!(
+            role_of(&references, "LOV-1995-03-03-10"),
+            Some(ReferenceRole::Repealed)
+        );
+        assert_eq!(
+            role_of(&references, "LOV-2016-06-17-42"),
+            Some(ReferenceRole::Repealed)
+        );
+        // "kan endres" under a Hjemmel heading amends the regulations, not the law.
+        assert_eq!(
+            role_of(&references, "LOV-2017-06-16-60"),
+            Some(ReferenceRole::Cited)
+        );
+        assert_eq!(
+            role_of(&references, "LOV-2024-12-20-90"),
+            Some(ReferenceRole::Enacting)
+        );
+    }
+
+    #[test]
+    fn heading_role_covers_only_the_law_its_opening_clause_names() {
+        let text = "Lov om endringer i barnehageloven mv. II Endringer i andre lover I lov 1. \
+                    januar 2000 nr. 1 om barnehager gjøres følgende endring: § 5 skal lyde: Saker \
+                    etter lov 2. februar 2001 nr. 2 om barnevern behandles av kommunen.";
+        let references = extract_law_references(
+            text,
+            &LiteralHits::scan(text),
+            &mut ExtractionBudget::default(),
+        );
+
+        assert_eq!(
+            role_of(&references, "LOV-2000-01-01-1"),
+            Some(ReferenceRole::Amended)
+        );
+        assert_eq!(
+            role_of(&references, "LOV-2001-02-02-2"),
+            Some(ReferenceRole::Cited)
+        );
+    }
+
+    #[test]
+    fn reference_role_keeps_strongest_role_across_occurrences() {
+        let text = "Forskriften er gitt med hjemmel i lov 16. juni 2017 nr. 60. \
+                    I lov 16. juni 2017 nr. 60 om forvaltning gjøres følgende endringer: \
+                    § 3 skal lyde:";
+        let references = extract_law_references(
+            text,
+            &LiteralHits::scan(text),
+            &mut ExtractionBudget::default(),
+        );
+
+        assert_eq!(references.len(), 1);
+        assert_eq!(references[0].role, ReferenceRole::Amended);
+        assert_eq!(references[0].occurrences, 2);
+    }
+
+    #[test]
+    fn references_are_returned_in_order_of_first_appearance() {
+        let text = include_str!("fixtures/references/roles.txt");
+        let ids: Vec<String> = extract_law_references(
+            text,
+            &LiteralHits::scan(text),
+            &mut ExtractionBudget::default(),
+        )
+        .into_iter()
+        .map(|r| r.id)
+        .collect();
+
+        assert_eq!(
+            ids,
+            vec![
+                "LOV-2017-06-16-60",
+                "LOV-2005-06-17-62",
+                "LOV-1995-03-03-10",
+            ]
+        );
+    }
+
+    #[test]
+    fn reference_evidence_uses_character_offsets() {
+        let text = "Første ledd i lov 16. juni 2017 nr 60 gjelder.";
+        let references = extract_law_references(
+            text,
+            &LiteralHits::scan(text),
+            &mut ExtractionBudget::default(),
+        );
+        let reference = &references[0];
+        let chars: Vec<char> = text.chars().collect();
+        let raw: String = chars[reference.start..reference.end].iter().collect();
+
+        assert_eq!(raw, reference.raw);
+        assert_eq!(reference.start, 14);
+        assert_eq!(reference.rule, RULE_LAW_DATE_NUMBER);
+        assert!(reference.snippet.contains("lov 16. juni 2017 nr 60"));
+    }
+
+    #[test]
+    fn confidence_rewards_title_and_penalizes_missing_period() {
+        let titled = extract_law_references(
+            "lov 16. juni 2017 nr. 60 om helseregistre",
+            &LiteralHits::scan("lov 16. juni 2017 nr. 60 om helseregistre"),
+            &mut ExtractionBudget::default(),
+        );
+        let bare = extract_law_references(
+            "lov 16. juni 2017 nr 60.",
+            &LiteralHits::scan("lov 16. juni 2017 nr 60."),
+            &mut ExtractionBudget::default(),
+        );
+
+        assert!(titled[0].confidence > bare[0].confidence);
+        assert!(titled[0].confidence <= 1.0);
+    }
+
+    #[test]
+    fn captures_cited_title_up_to_clause_verb() {
+        let text = "I lov 16. juni 2017 nr. 60 om helseregistre og behandling av \
+                    helseopplysninger gjøres følgende endringer:";
+        let references = extract_law_references(
+            text,
+            &LiteralHits::scan(text),
+            &mut ExtractionBudget::default(),
+        );
+
+        assert_eq!(
+            references[0].title.as_deref(),
+            Some("om helseregistre og behandling av helseopplysninger")
+        );
+        assert_eq!(
+            extract_law_references(
+                "jf. lov 16. juni 2017 nr. 60.",
+                &LiteralHits::scan("jf. lov 16. juni 2017 nr. 60."),
+                &mut ExtractionBudget::default()
+            )[0]
+            .title,
+            None
+        );
+    }
+
+    #[test]
+    fn captures_comma_titles_and_short_titles() {
+        let references = extract_law_references(
+            "etter lov 17. juni 2005 nr. 62 om arbeidsmiljø, arbeidstid og stillingsvern mv. \
+             (arbeidsmiljøloven) og lov 26. mars 1999 nr. 14 (skatteloven), lov 20. juni 2014 \
+             nr. 43 om helseregistre, lov 16. juni 2017 nr. 60 om helseregistre.",
+            &LiteralHits::scan(
+                "etter lov 17. juni 2005 nr. 62 om arbeidsmiljø, arbeidstid og stillingsvern mv. \
+             (arbeidsmiljøloven) og lov 26. mars 1999 nr. 14 (skatteloven), lov 20. juni 2014 \
+             nr. 43 om helseregistre, lov 16. juni 2017 nr. 60 om helseregistre.",
+            ),
+            &mut ExtractionBudget::default(),
+        );
+
+        assert_eq!(
+            references[0].title.as_deref(),
+            Some("om arbeidsmiljø, arbeidstid og stillingsvern mv.")
+        );
+        assert_eq!(
+            references[0].short_title.as_deref(),
+            Some("arbeidsmiljøloven")
+        );
+        assert_eq!(references[1].title, None);
+        assert_eq!(references[1].short_title.as_deref(), Some("skatteloven"));
+        assert_eq!(references[2].title.as_deref(), Some("om helseregistre"));
+        assert_eq!(references[2].short_title, None);
+    }
+
+    #[test]
+    fn reference_role_is_serialized_in_snake_case() {
+        let text = "Fra samme tid oppheves lov 3. mars 1995 nr. 10 om helseregistre.";
+        let references = extract_law_references(
+            text,
+            &LiteralHits::scan(text),
+            &mut ExtractionBudget::default(),
+        );
+        let json = serde_json::to_value(&references[0]).unwrap();
+
+        assert_eq!(json["role"], "repealed");
+        assert_eq!(json["occurrences"], 1);
+        assert_eq!(json["rule"], RULE_LAW_DATE_NUMBER);
+    }
+
+    #[test]
+    fn classifies_nynorsk_roles() {
+        let text = include_str!("fixtures/language/nynorsk.txt");
+        let references = extract_law_references(
+            text,
+            &LiteralHits::scan(text),
+            &mut ExtractionBudget::default(),
+        );
+
+        assert_eq!(references[0].id, "LOV-2023-06-09-30");
+        assert_eq!(references[0].role, ReferenceRole::Amended);
+        assert_eq!(
+            references[0].title.as_deref(),
+            Some("om grunnskoleopplæringa og den vidaregåande opplæringa")
+        );
+
+        let repealed = extract_law_references(
+            "Lov 3. mars 1995 nr. 10 om helseregister vert oppheva.",
+            &LiteralHits::scan("Lov 3. mars 1995 nr. 10 om helseregister vert oppheva."),
+            &mut ExtractionBudget::default(),
+        );
+        assert_eq!(repealed[0].role, ReferenceRole::Repealed);
+    }
+}
@@ -0,0 +1,230 @@
+use serde::Serialize;
+
+use crate::budget::ExtractionBudget;
+use crate::dates::{date_pattern, parse_date};
+use crate::prefilter::{Literal, LiteralHits};
+use crate::references::{cited_title, clause_end, clause_start, LawReference, ReferenceRole};
+use crate::snippet_around_match;
+
+const REPEAL_VERB_PATTERN: &str =
+    r"(?i)\b(?:oppheves|(?:vert|blir)\s+oppheva|(?:vert|blir)\s+opphevet)\b";
+
+/// When a repeal takes effect. Most repeals follow the new law ("Fra den tid loven trer i
+/// kraft, oppheves ..."), which is also assumed when the clause names no time.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub(crate) enum RepealEffect {
+    WithLaw,
+    Immediate,
+    ByRoyalDecree,
+    Date { date: String },
+}
+
+/// A law or regulation the lovvedtak repeals in full. Repeals of single provisions
+/// ("I lov ... oppheves § 7-3") amend the law and are not listed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub(crate) struct RepealedDocument {
+    pub(crate) id: String,
+    pub(crate) title: Option<String>,
+    pub(crate) effective: RepealEffect,
+    pub(crate) matched_snippet: String,
+}
+
+pub(crate) fn extract_repealed_documents(
+    text: &str,
+    literals: &LiteralHits,
+    budget: &mut ExtractionBudget,
+) -> Vec<RepealedDocument> {
+    // Pure repeal decisions often have no "§", but every repeal cites a law or regulation.
+    if !literals.contains(Literal::Lov) && !literals.contains(Literal::Forskrift) {
+        return Vec::new();
+    }
+    let verb_re = cached_regex!(REPEAL_VERB_PATTERN);
+    // A citation of a whole law or regulation, mapped to its Lovdata dokid prefix.
+    let document_re = cached_regex!(format!(
+        r"(?i)\b(lov|forskrift)\s+({})\s+nr\.?\s*(\d+)",
+        date_pattern()
+    ));
+    let mut repealed: Vec<RepealedDocument> = Vec::new();
+
+    for verb in verb_re.find_iter(text) {
+        let start = clause_start(text, verb.start());
+        let end = clause_end(text, verb.end());
+        let object = text[verb.end()..end].trim_start().to_lowercase();
+        if object.starts_with('§') || object.starts_with("kapittel") || object.starts_with("kap.")
+        {
+            continue;
+        }
+
+        let clause = &text[start..end];
+        let effective = repeal_effect(&clause.to_lowercase());
+
+        for cap in document_re.captures_iter(clause) {
+            let citation = cap.get(0).unwrap();
+            let (citation_start, citation_end) = (start + citation.start(), start + citation.end());
+            // "§ 5 i lov ... oppheves" names a provision of the cited law, and a citation
+            // separated from the verb by a provision belongs to an earlier clause.
+            if citation_start < verb.start()
+                && (text[citation_end..verb.start()].contains('§')
+                    || text[start..citation_start].trim_end().ends_with(" i"))
+            {
+                continue;
+            }
+            let Some(id) = document_id(&cap) else {
+                continue;
+            };
+            if repealed.iter().any(|document| document.id == id) {
+                continue;
+            }
+            if !budget.allows_match(repealed.len()) {
+                return repealed;
+            }
+
+            repealed.push(RepealedDocument {
+                id,
+                title: cited_title(text, citation_end),
+                effective: effective.clone(),
+                matched_snippet: snippet_around_match(
+                    text,
+                    citation_start.min(verb.start()),
+                    citation_end.max(verb.end()),
+                ),
+            });
+        }
+    }
+
+    repealed
+}
+
+/// Marks references to fully repealed laws as repealed, whatever else the clause says
+/// about them.
+pub(crate) fn mark_repealed_references(
+    references: &mut [LawReference],
+    repealed: &[RepealedDocument],
+) {
+    for reference in references.iter_mut() {
+        if repealed.iter().any(|document| document.id == reference.id) {
+            reference.role = ReferenceRole::Repealed;
+        }
+    }
+}
+
+fn document_id(cap: &regex::Captures<'_>) -> Option<String> {
+    let prefix = if cap[1].eq_ignore_ascii_case("lov") {
+        "LOV"
+    } else {
+        "FOR"
+    };
+    let date = parse_date(&cap[2])?;
+    Some(format!("{}-{}-{}", prefix, date, &cap[3]))
+}
+
+fn repeal_effect(clause: &str) -> RepealEffect {
+    let date_re = cached_regex!(format!(r"fr[aå]\s+(?:og\s+med\s+)?({})", date_pattern()));
+
+    if clause.contains("kongen bestemmer") || clause.contains("kongen fastset") {
+        RepealEffect::ByRoyalDecree
+    } else if let Some(date) = date_re.captures(clause).and_then(|cap| parse_date(&cap[1])) {
+        RepealEffect::Date { date }
+    } else if clause.contains("straks") {
+        RepealEffect::Immediate
+    } else {
+        RepealEffect::WithLaw
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::references::extract_law_references;
+
+    #[test]
+    fn extracts_full_law_repeal_with_law() {
+        let text = include_str!("fixtures/references/roles.txt");
+        let repealed = extract_repealed_documents(
+            text,
+            &LiteralHits::scan(text),
+            &mut ExtractionBudget::default(),
+        );
+
+        assert_eq!(repealed.len(), 1);
+        assert_eq!(repealed[0].id, "LOV-1995-03-03-10");
+        assert_eq!(repealed[0].title.as_deref(), Some("om helseregistre"));
+        assert_eq!(repealed[0].effective, RepealEffect::WithLaw);
+    }
+
+    #[test]
+    fn extracts_repealed_laws_and_regulations_with_effect() {
+        let repealed = extract_repealed_documents(
+            include_str!("fixtures/repeals/repeals.txt"),
+            &LiteralHits::scan(include_str!("fixtures/repeals/repeals.txt")),
+            &mut ExtractionBudget::default(),
+        );
+        let ids: Vec<&str> = repealed
+            .iter()
+            .map(|document| document.id.as_str())
+            .collect();
+
+        assert_eq!(
+            ids,
+            vec![
+                "LOV-1981-06-05-40",
+                "FOR-2005-07-01-700",
+                "LOV-1999-03-26-17"
+            ]
+        );
+        assert_eq!(
+            repealed[0].effective,
+            RepealEffect::Date {
+                date: "2027-01-01".to_string()
+            }
+        );
+        assert_eq!(repealed[1].effective, repealed[0].effective);
+        assert_eq!(repealed[2].effective, RepealEffect::ByRoyalDecree);
+    }
+
+    #[test]
+    fn skips_provision_repeals() {
+        let text = "I lov 16. juni 1989 nr. 69 om forsikringsavtaler oppheves § 7-3. \
+                    § 5 i lov 3. mars 1995 nr. 10 om helseregistre oppheves.";
+        assert!(extract_repealed_documents(
+            text,
+            &LiteralHits::scan(text),
+            &mut ExtractionBudget::default()
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn marks_references_to_repealed_laws() {
+        let text = include_str!("fixtures/repeals/repeals.txt");
+        let mut references = extract_law_references(
+            "Loven erstatter lov 5. juni 1981 nr. 40 om skilsmisse.",
+            &LiteralHits::scan("Loven erstatter lov 5. juni 1981 nr. 40 om skilsmisse."),
+            &mut ExtractionBudget::default(),
+        );
+        assert_eq!(references[0].role, ReferenceRole::Cited);
+
+        mark_repealed_references(
+            &mut references,
+            &extract_repealed_documents(
+                text,
+                &LiteralHits::scan(text),
+                &mut ExtractionBudget::default(),
+            ),
+        );
+        assert_eq!(references[0].role, ReferenceRole::Repealed);
+    }
+
+    #[test]
+    fn accepts_nynorsk_repeal() {
+        let repealed = extract_repealed_documents(
+            "Lov 13. juni 1980 nr. 35 om kulturminne vert oppheva straks.",
+            &LiteralHits::scan("Lov 13. juni 1980 nr. 35 om kulturminne vert oppheva straks."),
+            &mut ExtractionBudget::default(),
+        );
+
+        assert_eq!(repealed.len(), 1);
+        assert_eq!(repealed[0].effective, RepealEffect::Immediate);
+    }
+}
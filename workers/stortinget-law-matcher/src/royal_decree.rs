@@ -0,0 +1,307 @@
+use serde::{Deserialize, Serialize};
+
+use crate::budget::ExtractionBudget;
+use crate::catalogue::normalize_title;
+use crate::enforcement::{
+    extract_enforcement_date, months_after, Enforcement, EnforcementAnchor, EnforcementParseResult,
+    RelativeEnforcement,
+};
+use crate::prefilter::LiteralHits;
+use crate::references::extract_law_references;
+use crate::ENFORCEMENT_SNIPPET_NONE;
+
+/// Lovtidend titles of resolutions that put a law into force, in bokmål and nynorsk.
+const RESOLUTION_TITLE_PREFIXES: [&str; 2] = ["ikrafttredelse av ", "ikraftsetjing av "];
+
+/// A law is sanctioned, and gets its LOV ID, within weeks of Stortinget's decision. A
+/// same-titled law sanctioned later than this is a different amendment.
+const MAX_SANCTION_DELAY_MONTHS: u32 = 3;
+
+/// A proposal still stored as `KONGEN_BESTEMMER`, or as `RELATIV` with the clause in
+/// `enforcement_relative`, as served by the pending-proposals endpoint.
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct PendingProposal {
+    pub(crate) id: String,
+    pub(crate) enacted_law_title: Option<String>,
+    #[serde(default)]
+    pub(crate) decision_date: Option<String>,
+    #[serde(default)]
+    pub(crate) enforcement_relative: Option<RelativeEnforcement>,
+}
+
+/// One published document in the configured Lovtidend/Lovdata listing: an ikrafttredelse
+/// resolution or a law. `published` is the Lovtidend publication date, which clauses
+/// counted from promulgation ("seks måneder etter kunngjøringen") need.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub(crate) struct ResolutionEntry {
+    pub(crate) id: String,
+    pub(crate) title: String,
+    pub(crate) url: String,
+    #[serde(default)]
+    pub(crate) published: Option<String>,
+}
+
+/// Finds the ikrafttredelse resolution for a pending proposal. The resolution title cites
+/// the sanctioned law ("Ikrafttredelse av lov 20. juni 2025 nr. 85 om endringer i
+/// opplæringslova"); its title must equal the enacted law's and its date must fall in the
+/// sanction window after the decision. Amendment titles repeat across years, so several
+/// matches without a decision date to tell them apart are left unresolved.
+pub(crate) fn find_resolution<'a>(
+    proposal: &PendingProposal,
+    entries: &'a [ResolutionEntry],
+) -> Option<&'a ResolutionEntry> {
+    find_for_proposal(proposal, entries, |entry| resolved_law(&entry.title))
+}
+
+/// Finds the listed law itself for a proposal whose entry into force counts from its
+/// sanction or promulgation, with the same title and sanction-window rules.
+pub(crate) fn find_sanctioned_law<'a>(
+    proposal: &PendingProposal,
+    entries: &'a [ResolutionEntry],
+) -> Option<&'a ResolutionEntry> {
+    find_for_proposal(proposal, entries, listed_law)
+}
+
+/// The entry whose law, as read by `law` into sanction date and title, is the proposal's.
+fn find_for_proposal<'a>(
+    proposal: &PendingProposal,
+    entries: &'a [ResolutionEntry],
+    law: impl Fn(&ResolutionEntry) -> Option<(String, String)>,
+) -> Option<&'a ResolutionEntry> {
+    let wanted = normalize_title(proposal.enacted_law_title.as_deref()?);
+    if wanted.is_empty() {
+        return None;
+    }
+    let decision_date = proposal.decision_date.as_deref();
+    let latest_sanction =
+        decision_date.and_then(|date| months_after(date, MAX_SANCTION_DELAY_MONTHS));
+
+    let mut matches: Vec<(&ResolutionEntry, String)> = entries
+        .iter()
+        .filter_map(|entry| {
+            let (law_date, title) = law(entry)?;
+            (normalize_title(&title) == wanted).then_some((entry, law_date))
+        })
+        .filter(|(_, law_date)| {
+            decision_date.is_none_or(|date| law_date.as_str() >= date)
+                && latest_sanction
+                    .as_deref()
+                    .is_none_or(|latest| law_date.as_str() <= latest)
+        })
+        .collect();
+
+    if decision_date.is_none() && matches.len() > 1 {
+        return None;
+    }
+    matches.sort_by(|a, b| a.1.cmp(&b.1));
+    matches.first().map(|(entry, _)| *entry)
+}
+
+/// The sanction date and title of the law a resolution title puts into force.
+fn resolved_law(resolution_title: &str) -> Option<(String, String)> {
+    let lowered = resolution_title.trim().to_lowercase();
+    if !RESOLUTION_TITLE_PREFIXES
+        .iter()
+        .any(|prefix| lowered.starts_with(prefix))
+    {
+        return None;
+    }
+
+    let reference = extract_law_references(
+        resolution_title,
+        &LiteralHits::scan(resolution_title),
+        &mut ExtractionBudget::default(),
+    )
+    .into_iter()
+    .next()?;
+    let law_date = reference.id.get(4..14)?.to_string();
+    Some((law_date, reference.title?))
+}
+
+/// The sanction date, from the LOV ID, and title of a law in the listing.
+fn listed_law(entry: &ResolutionEntry) -> Option<(String, String)> {
+    let law_date = entry.id.strip_prefix("LOV-")?.get(..10)?;
+    Some((law_date.to_string(), entry.title.clone()))
+}
+
+/// Resolves a stored relative clause against the listed law: its sanction date, or the
+/// date it was published in Lovtidend. `None` while the anchor date is not known.
+pub(crate) fn resolve_relative(
+    relative: &RelativeEnforcement,
+    law: &ResolutionEntry,
+) -> Option<EnforcementParseResult> {
+    let anchor_date = match relative.anchor {
+        EnforcementAnchor::Sanction => listed_law(law)?.0,
+        EnforcementAnchor::Promulgation => law.published.clone()?,
+    };
+    let date = relative.resolve(&anchor_date)?;
+    let snippet = if relative.matched_snippet.is_empty() {
+        ENFORCEMENT_SNIPPET_NONE.to_string()
+    } else {
+        relative.matched_snippet.clone()
+    };
+
+    Some(EnforcementParseResult::new(
+        Enforcement::date(date),
+        "relative",
+        snippet,
+    ))
+}
+
+/// Reads the dates out of a resolution text. Only a fixed date or a per-provision
+/// schedule resolves the proposal; anything else leaves it as `KONGEN_BESTEMMER`.
+pub(crate) fn resolve_enforcement(resolution_text: &str) -> Option<EnforcementParseResult> {
+    let result = extract_enforcement_date(resolution_text, &LiteralHits::scan(resolution_text));
+    matches!(
+        result.value,
+        Enforcement::Date { .. } | Enforcement::Schedule
+    )
+    .then_some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dates::PeriodUnit;
+    use crate::enforcement::{ProvisionScope, RelativeOffset};
+
+    fn entries() -> Vec<ResolutionEntry> {
+        serde_json::from_str(include_str!("fixtures/royal_decree/listing.json")).unwrap()
+    }
+
+    fn proposal(title: &str, decision_date: Option<&str>) -> PendingProposal {
+        PendingProposal {
+            id: "proposal-1".to_string(),
+            enacted_law_title: Some(title.to_string()),
+            decision_date: decision_date.map(str::to_string),
+            enforcement_relative: None,
+        }
+    }
+
+    fn relative(anchor: EnforcementAnchor, offset: RelativeOffset) -> RelativeEnforcement {
+        RelativeEnforcement {
+            anchor,
+            offset,
+            matched_snippet: String::new(),
+        }
+    }
+
+    #[test]
+    fn finds_resolution_sanctioned_after_decision() {
+        let entries = entries();
+        let pending = proposal("Lov om endringer i opplæringslova", Some("2025-06-10"));
+
+        let entry = find_resolution(&pending, &entries).expect("resolution should match");
+        assert_eq!(entry.id, "FOR-2025-12-12-2400");
+    }
+
+    #[test]
+    fn skips_same_title_outside_sanction_window() {
+        let entries = entries();
+        let pending = proposal("Lov om endringer i opplæringslova", Some("2024-03-01"));
+
+        assert_eq!(find_resolution(&pending, &entries), None);
+    }
+
+    #[test]
+    fn leaves_repeated_titles_unresolved_without_decision_date() {
+        let entries = entries();
+        let pending = proposal("Lov om endringer i skatteloven", None);
+
+        assert_eq!(find_resolution(&pending, &entries), None);
+    }
+
+    #[test]
+    fn matches_nynorsk_resolution_title() {
+        let entries = entries();
+        let pending = proposal("Lov om endringar i kulturlova", Some("2025-05-20"));
+
+        let entry = find_resolution(&pending, &entries).expect("resolution should match");
+        assert_eq!(entry.id, "FOR-2025-09-05-1800");
+    }
+
+    #[test]
+    fn resolves_per_provision_dates() {
+        let result = resolve_enforcement(include_str!("fixtures/royal_decree/resolution.txt"))
+            .expect("resolution should carry dates");
+
+        assert_eq!(result.value, Enforcement::Schedule);
+        assert_eq!(result.schedule.len(), 2);
+        assert_eq!(result.schedule[0].scope, ProvisionScope::WholeLaw);
+        assert_eq!(
+            result.schedule[1].scope,
+            ProvisionScope::Provisions(vec!["§ 12-3".to_string()])
+        );
+        assert_eq!(result.schedule[1].value.legacy_value(), "2026-08-01");
+    }
+
+    #[test]
+    fn resolves_relative_clause_from_published_law() {
+        let entries = entries();
+        let pending = proposal("Lov om endringer i finansforetaksloven", Some("2025-06-10"));
+        let law = find_sanctioned_law(&pending, &entries).expect("law should be listed");
+
+        let after_promulgation = relative(
+            EnforcementAnchor::Promulgation,
+            RelativeOffset::After {
+                amount: 6,
+                unit: PeriodUnit::Months,
+            },
+        );
+        let after_sanction = relative(
+            EnforcementAnchor::Sanction,
+            RelativeOffset::DayInFollowingYear { day: 1, month: 7 },
+        );
+
+        assert_eq!(law.id, "LOV-2025-06-20-86");
+        assert_eq!(
+            resolve_relative(&after_promulgation, law).map(|result| result.value),
+            Some(Enforcement::date("2025-12-24".to_string()))
+        );
+        assert_eq!(
+            resolve_relative(&after_sanction, law).map(|result| result.value),
+            Some(Enforcement::date("2026-07-01".to_string()))
+        );
+        assert_eq!(find_resolution(&pending, &entries), None);
+    }
+
+    #[test]
+    fn relative_clause_waits_for_publication_date() {
+        let unpublished = ResolutionEntry {
+            published: None,
+            ..entries()
+                .into_iter()
+                .find(|entry| entry.id.starts_with("LOV-"))
+                .unwrap()
+        };
+        let clause = relative(EnforcementAnchor::Promulgation, RelativeOffset::OnAnchor);
+
+        assert_eq!(resolve_relative(&clause, &unpublished), None);
+    }
+
+    #[test]
+    fn reads_stored_relative_clause_without_snippet() {
+        let pending: PendingProposal = serde_json::from_value(serde_json::json!({
+            "id": "proposal-1",
+            "enacted_law_title": "Lov om endringer i finansforetaksloven",
+            "enforcement_relative": {
+                "anchor": "promulgation",
+                "offset": { "kind": "after", "amount": 6, "unit": "months" }
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(
+            pending.enforcement_relative.map(|relative| relative.offset),
+            Some(RelativeOffset::After {
+                amount: 6,
+                unit: PeriodUnit::Months
+            })
+        );
+    }
+
+    #[test]
+    fn ignores_resolution_without_dates() {
+        assert!(resolve_enforcement("Loven trer i kraft fra den tid Kongen bestemmer.").is_none());
+    }
+}
@@ -0,0 +1,121 @@
+/// A section heading: the § number followed by its capitalised title ("§ 12 Forskrifter").
+/// Citations inside running text ("etter § 5 første ledd") are followed by lowercase.
+const SECTION_HEADING_PATTERN: &str = r"§\s*(\d+(?:-\d+)?(?:\s?[a-z]\b)?)\s+[A-ZÆØÅ]";
+
+/// A part heading: the Roman numeral of a lovvedtak part, at the start of a line or after a
+/// sentence end, followed by its capitalised title ("II Endringer i andre lover"). A part
+/// opening "I lov ... gjøres følgende endringer" has no title and is not matched.
+const PART_HEADING_PATTERN: &str = r"(?m)(?:^|[.:;!?]\s+)([IVX]{1,6})\s+([A-ZÆØÅ][a-zæøå]+)";
+
+/// The § whose heading most recently precedes byte offset `idx`, as "§ 12".
+pub(crate) fn section_at(text: &str, idx: usize) -> Option<String> {
+    last_heading(text, idx).map(|(_, number)| {
+        format!(
+            "§ {}",
+            number.split_whitespace().collect::<Vec<_>>().join(" ")
+        )
+    })
+}
+
+/// Byte offset where the section containing `idx` starts: its heading, or the start of the
+/// text.
+pub(crate) fn section_start(text: &str, idx: usize) -> usize {
+    last_heading(text, idx).map(|(start, _)| start).unwrap_or(0)
+}
+
+/// The start and number of the last heading that ends by `idx`. Extractors ask for the
+/// section of every match, so rather than scanning the text from the top, the pattern is
+/// only tried at the "§" signs before `idx`, nearest first.
+fn last_heading(text: &str, idx: usize) -> Option<(usize, &str)> {
+    let heading_re = cached_regex!(format!("^{}", SECTION_HEADING_PATTERN));
+    text[..idx].rmatch_indices('§').find_map(|(start, _)| {
+        heading_re
+            .captures(&text[start..idx])
+            .map(|cap| (start, cap.get(1).unwrap().as_str()))
+    })
+}
+
+/// Byte offsets where the titles of part and § headings start, in text order. A bare "I"
+/// only counts as the first part heading, before any "§"; anywhere else it is the
+/// preposition ("I Finnmark gjelder ...").
+pub(crate) fn heading_titles(text: &str) -> Vec<usize> {
+    let part_re = cached_regex!(PART_HEADING_PATTERN);
+    let section_re = cached_regex!(SECTION_HEADING_PATTERN);
+
+    let first_section = text.find('§').unwrap_or(text.len());
+    let mut titles: Vec<usize> = part_re
+        .captures_iter(text)
+        .enumerate()
+        .filter(|(idx, cap)| {
+            &cap[1] != "I" || (*idx == 0 && cap.get(1).unwrap().start() < first_section)
+        })
+        .map(|(_, cap)| cap.get(2).unwrap().start())
+        .chain(section_re.find_iter(text).map(|m| {
+            let title = text[..m.end()].chars().next_back().unwrap();
+            m.end() - title.len_utf8()
+        }))
+        .collect();
+    titles.sort_unstable();
+    titles
+}
+
+/// Byte offset where the section containing `idx` ends: the next section heading, or the
+/// end of the text.
+pub(crate) fn section_end(text: &str, idx: usize) -> usize {
+    let heading_re = cached_regex!(SECTION_HEADING_PATTERN);
+    heading_re
+        .find_at(text, idx)
+        .map(|m| m.start())
+        .unwrap_or(text.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_enclosing_section_heading() {
+        let text = "§ 1 Formål Loven skal styrke vernet. § 2 Virkeområde Loven gjelder etter § 1 \
+                    første ledd for alle.";
+        let idx = text.find("for alle").unwrap();
+
+        assert_eq!(section_at(text, idx).as_deref(), Some("§ 2"));
+        assert_eq!(section_at(text, 0), None);
+        assert_eq!(section_end(text, 1), text.find("§ 2").unwrap());
+        assert_eq!(section_end(text, idx), text.len());
+        assert_eq!(section_start(text, idx), text.find("§ 2").unwrap());
+        assert_eq!(section_start(text, text.find("Formål").unwrap()), 0);
+    }
+
+    #[test]
+    fn finds_part_and_section_heading_titles() {
+        let text = "Lov om endringer i postloven I I lov 4. juni 2015 nr. 39 gjøres følgende \
+                    endring: § 7 skal lyde: Tekst. II Ikrafttredelse Loven trer i kraft straks. \
+                    § 3 Øvrige regler gjelder.";
+        let titles: Vec<&str> = heading_titles(text)
+            .into_iter()
+            .map(|start| &text[start..start + text[start..].find(' ').unwrap()])
+            .collect();
+
+        assert_eq!(titles, vec!["Ikrafttredelse", "Øvrige"]);
+    }
+
+    #[test]
+    fn ignores_the_preposition_i_before_a_proper_noun() {
+        let text = "Lov om reindrift. I Endringer i reindriftsloven § 2 skal lyde: Tekst. II \
+                    Endringer i andre lover Loven gjelder hele landet. I Finnmark gjelder \
+                    egne regler. I Norge ellers gjelder loven.";
+        let titles: Vec<&str> = heading_titles(text)
+            .into_iter()
+            .map(|start| &text[start..start + text[start..].find(' ').unwrap()])
+            .collect();
+
+        assert_eq!(titles, vec!["Endringer", "Endringer"]);
+    }
+
+    #[test]
+    fn reads_chapter_numbered_and_lettered_sections() {
+        let text = "§ 3-5 a Depositum Leieren kan stille depositum.";
+        assert_eq!(section_at(text, text.len()).as_deref(), Some("§ 3-5 a"));
+    }
+}
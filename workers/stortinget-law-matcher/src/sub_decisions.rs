@@ -0,0 +1,232 @@
+use serde::Serialize;
+
+use crate::budget::ExtractionBudget;
+use crate::catalogue::{validate_references, LawCatalogue};
+use crate::enacted_law::{identify_enacted_law, EnactedLaw};
+use crate::enforcement::{
+    extract_enforcement_date, Enforcement, EnforcementParseResult, PRIMARY_RULE_SUB_DECISIONS,
+};
+use crate::prefilter::LiteralHits;
+use crate::references::{extract_law_references, LawReference};
+use crate::repeals::{extract_repealed_documents, mark_repealed_references};
+
+/// One law inside a lovvedtak. A samlevedtak ("A. Lov om ...", "B. Lov om ...") yields one
+/// entry per lettered law; any other decision yields a single unlabelled entry. Reference
+/// offsets are relative to the segment, which starts `offset` characters into the text.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub(crate) struct SubDecision {
+    pub(crate) label: Option<String>,
+    pub(crate) offset: usize,
+    pub(crate) enacted_law: Option<EnactedLaw>,
+    pub(crate) references: Vec<LawReference>,
+    pub(crate) enforcement: EnforcementParseResult,
+}
+
+/// Splits the text at lettered "X. Lov om" headings when there are at least two of them,
+/// with each segment's byte offset, and returns nothing otherwise. Text before the first
+/// heading (the "Vedtak til lov" preamble) belongs to no law.
+pub(crate) fn split_sub_decisions(text: &str) -> Vec<(String, usize, &str)> {
+    let heading_re = cached_regex!(r"\b([A-Z])\.\s+Lov\s+om\s");
+    let starts: Vec<(usize, String)> = heading_re
+        .captures_iter(text)
+        .filter_map(|cap| Some((cap.get(0)?.start(), cap[1].to_string())))
+        .collect();
+
+    if starts.len() < 2 {
+        return Vec::new();
+    }
+
+    starts
+        .iter()
+        .enumerate()
+        .map(|(idx, (start, label))| {
+            let end = starts
+                .get(idx + 1)
+                .map(|(next, _)| *next)
+                .unwrap_or(text.len());
+            (label.clone(), *start, text[*start..end].trim_end())
+        })
+        .collect()
+}
+
+/// One entry per lettered law of a samlevedtak, and each law's references, up to the
+/// budget's match limit. Laws a segment repeals in full are marked as repealed there, as at
+/// the top level. Any other decision yields no entries here; see `whole_decision`.
+pub(crate) fn extract_sub_decisions(
+    text: &str,
+    literals: &LiteralHits,
+    catalogue: Option<&LawCatalogue>,
+    budget: &mut ExtractionBudget,
+) -> Vec<SubDecision> {
+    let mut sub_decisions = Vec::new();
+    for (label, start, segment) in split_sub_decisions(text) {
+        if !budget.allows_match(sub_decisions.len()) {
+            break;
+        }
+        let literals = literals.within(start..start + segment.len());
+        let mut references = extract_law_references(segment, &literals, budget);
+        if let Some(catalogue) = catalogue {
+            validate_references(&mut references, catalogue);
+        }
+        let repealed_documents = extract_repealed_documents(segment, &literals, budget);
+        mark_repealed_references(&mut references, &repealed_documents);
+        sub_decisions.push(SubDecision {
+            label: Some(label),
+            offset: text[..start].chars().count(),
+            enacted_law: identify_enacted_law(segment, &references),
+            references,
+            enforcement: extract_enforcement_date(segment, &literals),
+        });
+    }
+
+    sub_decisions
+}
+
+/// The single unlabelled entry for a decision that is not a samlevedtak, built from the
+/// results already read off the whole text rather than extracting them a second time.
+pub(crate) fn whole_decision(
+    enacted_law: Option<EnactedLaw>,
+    references: Vec<LawReference>,
+    enforcement: EnforcementParseResult,
+) -> SubDecision {
+    SubDecision {
+        label: None,
+        offset: 0,
+        enacted_law,
+        references,
+        enforcement,
+    }
+}
+
+/// Overall enforcement value for the proposal row: the shared value when every law enters
+/// into force the same way, otherwise `FLERE_DATOER`.
+pub(crate) fn combined_enforcement(
+    sub_decisions: &[SubDecision],
+) -> Option<EnforcementParseResult> {
+    let (first, rest) = sub_decisions.split_first()?;
+    if rest.is_empty() {
+        return None;
+    }
+
+    if rest
+        .iter()
+        .all(|sub| sub.enforcement.value == first.enforcement.value)
+    {
+        return Some(first.enforcement.clone());
+    }
+
+    let mut combined = EnforcementParseResult::new(
+        Enforcement::Schedule,
+        "sub_decisions",
+        first.enforcement.matched_snippet.clone(),
+    );
+    combined.primary_rule = PRIMARY_RULE_SUB_DECISIONS;
+    Some(combined)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::budget::Truncation;
+    use crate::enacted_law::EnactedLawKind;
+    use crate::references::ReferenceRole;
+
+    #[test]
+    fn splits_lettered_laws_with_own_results() {
+        let text = include_str!("fixtures/sub_decisions/samlevedtak.txt");
+        let subs = extract_sub_decisions(
+            text,
+            &LiteralHits::scan(text),
+            None,
+            &mut ExtractionBudget::default(),
+        );
+
+        assert_eq!(subs.len(), 2);
+        assert_eq!(subs[0].label.as_deref(), Some("A"));
+        assert_eq!(subs[1].label.as_deref(), Some("B"));
+
+        let first = subs[0].enacted_law.as_ref().unwrap();
+        assert_eq!(first.kind, EnactedLawKind::Amendment);
+        assert_eq!(subs[0].references[0].id, "LOV-1999-03-26-14");
+        assert_eq!(subs[0].enforcement.value, Enforcement::Immediate);
+
+        let second = subs[1].enacted_law.as_ref().unwrap();
+        assert_eq!(second.kind, EnactedLawKind::Amendment);
+        assert_eq!(subs[1].references[0].id, "LOV-2009-06-19-58");
+        assert_eq!(subs[1].enforcement.value.legacy_value(), "2027-01-01");
+    }
+
+    #[test]
+    fn segment_offsets_are_character_positions() {
+        let text = include_str!("fixtures/sub_decisions/samlevedtak.txt");
+        let subs = extract_sub_decisions(
+            text,
+            &LiteralHits::scan(text),
+            None,
+            &mut ExtractionBudget::default(),
+        );
+        let chars: Vec<char> = text.chars().collect();
+        let heading: String = chars[subs[1].offset..subs[1].offset + 10].iter().collect();
+
+        assert_eq!(heading, "B. Lov om ");
+    }
+
+    #[test]
+    fn single_law_decision_is_not_split() {
+        let text = include_str!("fixtures/references/roles.txt");
+        let subs = extract_sub_decisions(
+            text,
+            &LiteralHits::scan(text),
+            None,
+            &mut ExtractionBudget::default(),
+        );
+
+        assert!(subs.is_empty());
+        assert_eq!(combined_enforcement(&subs), None);
+    }
+
+    #[test]
+    fn stops_at_the_match_limit() {
+        let text = include_str!("fixtures/sub_decisions/samlevedtak.txt");
+        let mut budget = ExtractionBudget::new(text.len(), 1);
+        let subs = extract_sub_decisions(text, &LiteralHits::scan(text), None, &mut budget);
+
+        assert_eq!(subs.len(), 1);
+        assert_eq!(subs[0].label.as_deref(), Some("A"));
+        assert_eq!(budget.truncation(), &[Truncation::Matches]);
+    }
+
+    #[test]
+    fn marks_laws_a_segment_repeals() {
+        // Under an "Endringer" heading the clause alone would read the repealed law as amended.
+        let text = "A. Lov om endringer i skatteloven I I lov 26. mars 1999 nr. 14 om skatt \
+                    gjøres følgende endring: § 5-15 skal lyde: Tekst. B. Lov om endringer i \
+                    sjøfartslovgivningen mv. I Endringer i andre lover Lov 30. mai 1975 nr. 18 om \
+                    sjømenn oppheves. II Loven trer i kraft straks.";
+        let subs = extract_sub_decisions(
+            text,
+            &LiteralHits::scan(text),
+            None,
+            &mut ExtractionBudget::default(),
+        );
+
+        assert_eq!(subs[0].references[0].role, ReferenceRole::Amended);
+        assert_eq!(subs[1].references[0].id, "LOV-1975-05-30-18");
+        assert_eq!(subs[1].references[0].role, ReferenceRole::Repealed);
+    }
+
+    #[test]
+    fn combined_enforcement_reports_multiple_dates_when_laws_differ() {
+        let text = include_str!("fixtures/sub_decisions/samlevedtak.txt");
+        let combined = combined_enforcement(&extract_sub_decisions(
+            text,
+            &LiteralHits::scan(text),
+            None,
+            &mut ExtractionBudget::default(),
+        ))
+        .unwrap();
+
+        assert_eq!(combined.value, Enforcement::Schedule);
+        assert_eq!(combined.source, "sub_decisions");
+    }
+}
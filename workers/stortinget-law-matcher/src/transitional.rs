@@ -0,0 +1,206 @@
+use serde::Serialize;
+
+use crate::budget::ExtractionBudget;
+use crate::dates::{find_date_expressions, DateExpression};
+use crate::prefilter::{Literal, LiteralHits};
+use crate::{clamp_to_char_boundary_end, clamp_to_char_boundary_start, truncate_chars};
+
+/// A transitional section heading, optionally numbered ("III Overgangsbestemmelser",
+/// "§ 12 Ikrafttredelse og overgangsbestemmelser"). Only capitalised headings count, so
+/// "Kongen kan gi overgangsregler" in running text does not open a section.
+const HEADING_PATTERN: &str = r"(?:(§\s*\d+(?:-\d+)?(?:\s?[a-z]\b)?|\b[IVX]+)\s+)?(?:(?i:ikrafttredelse|ikraftsetjing)\s+og\s+overgangs\w+|Overgangs(?:bestemmelser|bestemmelse|regler|regel|reglar|føresegner|føresegn))\b";
+
+/// The next part ("IV Endringer ...") or section ("§ 13 Endringer ...") ends the
+/// transitional rules.
+const SECTION_END_PATTERN: &str = r"\s(?:[IVX]{2,}|V)\s+[A-ZÆØÅ§]|§\s*\d+(?:-\d+)?\s+[A-ZÆØÅ]";
+
+/// The word before a deadline the old rules run until. "før" marks a cut-off ("avtaler
+/// inngått før ...") and is only used when no such deadline is given.
+const DEADLINE_TRIGGER_PATTERN: &str =
+    r"(?i)\b(inntil|til\s+og\s+med|fr[ae]m\s+til|innan?|senest|før)\s+$";
+const DEADLINE_TRIGGER_BYTES: usize = 24;
+
+const MAX_SECTION_CHARS: usize = 1500;
+const MAX_EXCERPT_CHARS: usize = 400;
+
+/// One overgangsbestemmelser section. `section` is the § or part numbering the heading,
+/// `provisions` the sections its rules apply to. `start` and `end` are character positions
+/// of the section in the cleaned text, like reference offsets.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub(crate) struct TransitionalProvision {
+    pub(crate) section: Option<String>,
+    pub(crate) provisions: Vec<String>,
+    pub(crate) deadline: Option<String>,
+    pub(crate) excerpt: String,
+    pub(crate) start: usize,
+    pub(crate) end: usize,
+}
+
+pub(crate) fn extract_transitional_provisions(
+    text: &str,
+    literals: &LiteralHits,
+    budget: &mut ExtractionBudget,
+) -> Vec<TransitionalProvision> {
+    if !literals.contains(Literal::Section) {
+        return Vec::new();
+    }
+    let heading_re = cached_regex!(HEADING_PATTERN);
+    let end_re = cached_regex!(SECTION_END_PATTERN);
+
+    let mut provisions = Vec::new();
+    let mut pos = 0;
+
+    while let Some(cap) = heading_re.captures_at(text, pos) {
+        if !budget.allows_match(provisions.len()) {
+            break;
+        }
+        let heading = cap.get(0).unwrap();
+        let body_start = heading.end();
+        let limit = clamp_to_char_boundary_end(text, body_start + MAX_SECTION_CHARS);
+        let end = end_re
+            .find(&text[body_start..limit])
+            .map(|m| body_start + m.start())
+            .unwrap_or(limit);
+
+        let section = cap
+            .get(1)
+            .map(|m| m.as_str().split_whitespace().collect::<Vec<_>>().join(" "));
+        let body = &text[body_start..end];
+        provisions.push(TransitionalProvision {
+            provisions: affected_provisions(body, section.as_deref()),
+            deadline: deadline(body),
+            section,
+            excerpt: truncate_chars(text[heading.start()..end].trim(), MAX_EXCERPT_CHARS),
+            start: text[..heading.start()].chars().count(),
+            end: text[..end].chars().count(),
+        });
+        pos = end.max(body_start);
+    }
+
+    provisions
+}
+
+fn affected_provisions(body: &str, own_section: Option<&str>) -> Vec<String> {
+    let section_re = cached_regex!(r"§\s*\d+(?:-\d+)?(?:\s?[a-z]\b)?");
+    let mut provisions: Vec<String> = Vec::new();
+
+    for m in section_re.find_iter(body) {
+        let provision = m.as_str().split_whitespace().collect::<Vec<_>>().join(" ");
+        if Some(provision.as_str()) != own_section && !provisions.contains(&provision) {
+            provisions.push(provision);
+        }
+    }
+
+    provisions
+}
+
+/// A range runs until its last day ("inntil 1.–3. juli 2026" ends 3 July).
+fn deadline(body: &str) -> Option<String> {
+    let trigger_re = cached_regex!(DEADLINE_TRIGGER_PATTERN);
+    let mut cut_off = None;
+
+    for span in find_date_expressions(body) {
+        let window_start =
+            clamp_to_char_boundary_start(body, span.start.saturating_sub(DEADLINE_TRIGGER_BYTES));
+        let Some(trigger) = trigger_re.captures(&body[window_start..span.start]) else {
+            continue;
+        };
+        let date = match span.expression {
+            DateExpression::Date(date) | DateExpression::Range { end: date, .. } => date,
+            _ => continue,
+        };
+        if trigger[1].eq_ignore_ascii_case("før") {
+            cut_off.get_or_insert(date);
+        } else {
+            return Some(date);
+        }
+    }
+
+    cut_off
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracts_transitional_part() {
+        let text = include_str!("fixtures/transitional/transitional.txt");
+        let provisions = extract_transitional_provisions(
+            text,
+            &LiteralHits::scan(text),
+            &mut ExtractionBudget::default(),
+        );
+
+        assert_eq!(provisions.len(), 1);
+        let provision = &provisions[0];
+        assert_eq!(provision.section.as_deref(), Some("III"));
+        assert_eq!(provision.provisions, vec!["§ 3-5", "§ 3-6", "§ 3-8"]);
+        assert_eq!(provision.deadline.as_deref(), Some("2026-07-01"));
+        assert!(provision
+            .excerpt
+            .starts_with("III Overgangsbestemmelser Reglene"));
+        assert!(provision.excerpt.ends_with("1. juli 2026."));
+        let section: String = text
+            .chars()
+            .skip(provision.start)
+            .take(provision.end - provision.start)
+            .collect();
+        assert!(section.starts_with("III Overgangsbestemmelser"));
+        assert!(section.contains("avtaler inngått før"));
+    }
+
+    #[test]
+    fn extracts_numbered_section_with_cut_off_date() {
+        let text = "§ 11 Ikrafttredelse og overgangsbestemmelser Loven trer i kraft 1. juli 2026. \
+                    Loven gjelder ikke for saker reist før 1. juli 2026. § 12 Endringer i andre lover";
+        let provisions = extract_transitional_provisions(
+            text,
+            &LiteralHits::scan(text),
+            &mut ExtractionBudget::default(),
+        );
+
+        assert_eq!(provisions.len(), 1);
+        assert_eq!(provisions[0].section.as_deref(), Some("§ 11"));
+        assert!(provisions[0].provisions.is_empty());
+        assert_eq!(provisions[0].deadline.as_deref(), Some("2026-07-01"));
+    }
+
+    #[test]
+    fn accepts_nynorsk_heading() {
+        let text = "V Overgangsføresegner Forskrifter gitt med heimel i § 4 gjeld fram til 31. desember 2026.";
+        let provisions = extract_transitional_provisions(
+            text,
+            &LiteralHits::scan(text),
+            &mut ExtractionBudget::default(),
+        );
+
+        assert_eq!(provisions.len(), 1);
+        assert_eq!(provisions[0].provisions, vec!["§ 4"]);
+        assert_eq!(provisions[0].deadline.as_deref(), Some("2026-12-31"));
+    }
+
+    #[test]
+    fn ignores_mentions_in_running_text() {
+        let text = "Kongen kan gi overgangsregler. Loven trer i kraft straks.";
+        assert!(extract_transitional_provisions(
+            text,
+            &LiteralHits::scan(text),
+            &mut ExtractionBudget::default()
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn range_deadline_runs_until_its_last_day() {
+        let text =
+            "III Overgangsbestemmelser Søknader etter § 6 kan fremmes inntil 1.–3. juli 2026.";
+        let provisions = extract_transitional_provisions(
+            text,
+            &LiteralHits::scan(text),
+            &mut ExtractionBudget::default(),
+        );
+
+        assert_eq!(provisions[0].deadline.as_deref(), Some("2026-07-03"));
+    }
+}